@@ -0,0 +1,59 @@
+use crate::answer::checked_sum;
+use crate::input::Line;
+use crate::{Input, Result, Solution};
+
+mod tokenizer;
+
+pub use tokenizer::{DigitMatch, Vocabulary};
+
+pub struct Day01;
+
+/// The first and last digit of the line, as a two digit number.
+fn get_calib(
+    vocabulary: &Vocabulary,
+    line: &Line,
+) -> Result<u32> {
+    let first = vocabulary.first(&line.text);
+    let last = vocabulary.last(&line.text);
+    return match (first, last) {
+        (Some(first), Some(last)) => {
+            Ok(first.value * 10 + last.value)
+        }
+        _ => Err(line.error("no digits")),
+    };
+}
+
+fn sum_calibs(
+    vocabulary: &Vocabulary,
+    lines: &[Line],
+) -> Result<u32> {
+    let calibs = lines
+        .iter()
+        .map(|line| get_calib(vocabulary, line))
+        .collect::<Result<Vec<u32>>>()?;
+    return checked_sum(calibs);
+}
+
+impl Solution for Day01 {
+    type Parsed = Vec<Line>;
+    type Answer = Result<u32>;
+
+    fn parse(input: &Input) -> Result<Vec<Line>> {
+        let mut calib_lines = Vec::new();
+        for line in input.lines()? {
+            let line = line?;
+            if !line.text.is_empty() {
+                calib_lines.push(line);
+            }
+        }
+        return Ok(calib_lines);
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<u32> {
+        return sum_calibs(&Vocabulary::numerals(), lines);
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<u32> {
+        return sum_calibs(&Vocabulary::english(), lines);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::aho_corasick::{AhoCorasick, Match};
+
+/// A digit found in a line: its value, and the byte range it was
+/// written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DigitMatch {
+    pub value: u32,
+    pub start: usize,
+    pub len: usize,
+}
+
+impl DigitMatch {
+    pub fn end(&self) -> usize {
+        return self.start + self.len;
+    }
+}
+
+/// What counts as a digit: optionally the numerals themselves, plus
+/// any words spelling one out.
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    matcher: AhoCorasick<u32>,
+    /// The same words, read from the end of the line back.
+    backwards: AhoCorasick<u32>,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        return Vocabulary::new(Vec::<(String, u32)>::new());
+    }
+}
+
+impl Vocabulary {
+    /// Read each word as its value. A word given twice reads as
+    /// the later value.
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = (S, u32)>,
+        S: Into<String>,
+    {
+        let mut unique: Vec<(String, u32)> = Vec::new();
+        for (word, value) in words {
+            let word = word.into();
+            match unique.iter_mut().find(|x| x.0 == word) {
+                Some(known) => known.1 = value,
+                None => unique.push((word, value)),
+            }
+        }
+        let matcher = AhoCorasick::new(unique.iter().cloned());
+        let backwards =
+            AhoCorasick::reversed(unique.iter().cloned());
+        return Vocabulary {
+            words: unique,
+            matcher,
+            backwards,
+        };
+    }
+
+    /// Just `0` to `9`.
+    pub fn numerals() -> Self {
+        return Vocabulary::new(
+            (0..10).map(|x| (x.to_string(), x)),
+        );
+    }
+
+    /// The numerals, and `one` to `nine` spelled out, like the
+    /// puzzle's second part.
+    pub fn english() -> Self {
+        let words = [
+            "one", "two", "three", "four", "five", "six",
+            "seven", "eight", "nine",
+        ];
+        let numerals = (0..10).map(|x| (x.to_string(), x));
+        let spelled =
+            words.iter().map(|x| x.to_string()).zip(1..);
+        return Vocabulary::new(numerals.chain(spelled));
+    }
+
+    /// Also read `word` as `value`, instead of whatever it read as
+    /// before.
+    pub fn with_word(self, word: &str, value: u32) -> Self {
+        let mut words = self.words;
+        words.push((word.to_string(), value));
+        return Vocabulary::new(words);
+    }
+
+    /// Every digit in `line` by where it starts, overlapping ones
+    /// included. When several words start at the same place the
+    /// longest wins.
+    pub fn find_all(&self, line: &str) -> Vec<DigitMatch> {
+        let mut digits: Vec<DigitMatch> =
+            self.matcher.find_iter(line).map(digit).collect();
+        // longest first at each start, then drop the rest
+        digits
+            .sort_by_key(|x| (x.start, std::cmp::Reverse(x.len)));
+        digits.dedup_by_key(|x| x.start);
+        return digits;
+    }
+
+    /// The digit that starts first. Stops reading once no later
+    /// match could start earlier.
+    pub fn first(&self, line: &str) -> Option<DigitMatch> {
+        let longest = self.matcher.longest();
+        let mut first: Option<DigitMatch> = None;
+        for x in self.matcher.find_iter(line).map(digit) {
+            if let Some(best) = first {
+                // matches come by where they end, so everything
+                // from here on starts after `best`
+                if x.end() > best.start + longest {
+                    break;
+                }
+                let better = x.start < best.start
+                    || (x.start == best.start
+                        && x.len > best.len);
+                if !better {
+                    continue;
+                }
+            }
+            first = Some(x);
+        }
+        return first;
+    }
+
+    /// The digit that starts last, so one sharing letters with the
+    /// digit before it still counts. Reads from the end, so the
+    /// first match found is the one.
+    pub fn last(&self, line: &str) -> Option<DigitMatch> {
+        return self.backwards.find_iter(line).map(digit).next();
+    }
+}
+
+fn digit(found: Match<u32>) -> DigitMatch {
+    return DigitMatch {
+        value: *found.value,
+        start: found.start,
+        len: found.end - found.start,
+    };
+}
@@ -0,0 +1,182 @@
+use std::fmt::{self, Display};
+use std::ops::Index;
+use std::str::FromStr;
+
+use crate::input::Line;
+use crate::Result;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    pub const ALL: [Color; 3] =
+        [Color::Red, Color::Green, Color::Blue];
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Color::Red => write!(f, "red"),
+            Color::Green => write!(f, "green"),
+            Color::Blue => write!(f, "blue"),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(format!("unknown color {:?}", s)),
+        }
+    }
+}
+
+/// How many cubes of each color.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CubeSet {
+    // indexed by `Color as usize`
+    counts: [u32; 3],
+}
+
+impl CubeSet {
+    pub const fn new(red: u32, green: u32, blue: u32) -> Self {
+        return CubeSet {
+            counts: [red, green, blue],
+        };
+    }
+
+    pub fn with(mut self, color: Color, count: u32) -> Self {
+        self.counts[color as usize] = count;
+        return self;
+    }
+
+    /// The most of each color from either set.
+    pub fn max(&self, other: &CubeSet) -> CubeSet {
+        let mut counts = self.counts;
+        for (count, theirs) in counts.iter_mut().zip(other.counts)
+        {
+            *count = (*count).max(theirs);
+        }
+        return CubeSet { counts };
+    }
+
+    /// Whether every color could come out of `bag`.
+    pub fn fits_within(&self, bag: &CubeSet) -> bool {
+        return Color::ALL.iter().all(|x| self[*x] <= bag[*x]);
+    }
+
+    /// The counts multiplied together. Three `u32`s always fit.
+    pub fn power(&self) -> u128 {
+        return self
+            .counts
+            .iter()
+            .map(|x| u128::from(*x))
+            .product();
+    }
+}
+
+/// Counts and colors like a reveal, `3 blue, 4 red`.
+impl FromStr for CubeSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut cubes = CubeSet::default();
+        let mut seen = Vec::new();
+        for part in s.split(", ") {
+            let Some((count, color)) = part.split_once(' ')
+            else {
+                return Err(format!(
+                    "expected a count and a color, got {:?}",
+                    part
+                ));
+            };
+            let count = count.parse::<u32>().map_err(|e| {
+                format!("bad count {:?}: {}", count, e)
+            })?;
+            let color: Color = color.parse()?;
+            if seen.contains(&color) {
+                return Err(format!(
+                    "{} twice in one reveal",
+                    color
+                ));
+            }
+            seen.push(color);
+            cubes = cubes.with(color, count);
+        }
+        return Ok(cubes);
+    }
+}
+
+/// Like a reveal, leaving out the colors there are none of.
+impl Display for CubeSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cubes: Vec<String> = Color::ALL
+            .iter()
+            .filter(|x| self[**x] > 0)
+            .map(|x| format!("{} {}", self[*x], x))
+            .collect();
+        if cubes.is_empty() {
+            return write!(f, "no cubes");
+        }
+        return write!(f, "{}", cubes.join(", "));
+    }
+}
+
+impl Index<Color> for CubeSet {
+    type Output = u32;
+
+    fn index(&self, color: Color) -> &u32 {
+        return &self.counts[color as usize];
+    }
+}
+
+/// The cubes shown in one handful; colors not named weren't shown.
+pub type Reveal = CubeSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub reveals: Vec<Reveal>,
+}
+
+impl Game {
+    /// Parse `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+    pub fn parse(line: &Line) -> Result<Game> {
+        let (id, reveals) = line
+            .text
+            .strip_prefix("Game ")
+            .and_then(|x| x.split_once(": "))
+            .ok_or_else(|| {
+                line.error("expected \"Game N: ...\"")
+            })?;
+        let id = line.parse_int(id)?;
+        let reveals = reveals
+            .split("; ")
+            .map(|x| x.parse().map_err(|e: String| line.error(e)))
+            .collect::<Result<Vec<Reveal>>>()?;
+        return Ok(Game { id, reveals });
+    }
+
+    /// The fewest cubes of each color the game could be played with.
+    pub fn minimum(&self) -> CubeSet {
+        return self
+            .reveals
+            .iter()
+            .fold(CubeSet::default(), |x, reveal| x.max(reveal));
+    }
+
+    pub fn possible_with(&self, bag: &CubeSet) -> bool {
+        return self.minimum().fits_within(bag);
+    }
+}
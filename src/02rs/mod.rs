@@ -0,0 +1,45 @@
+use crate::answer::checked_sum;
+use crate::{Input, Result, Solution};
+
+mod game;
+mod report;
+
+pub use game::{Color, CubeSet, Game, Reveal};
+pub use report::{load_bag, BagReport, GameReport};
+
+pub struct Day02;
+
+/// What part 1 asks about, and `aoc bag` checks without `--bag`.
+pub const BAG: CubeSet = CubeSet::new(12, 13, 14);
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+    type Answer = Result<u128>;
+
+    fn parse(input: &Input) -> Result<Vec<Game>> {
+        let mut games = Vec::new();
+        for line in input.lines()? {
+            let line = line?;
+            if line.text.is_empty() {
+                continue;
+            }
+            games.push(Game::parse(&line)?);
+        }
+        return Ok(games);
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<u128> {
+        return checked_sum(
+            games
+                .iter()
+                .filter(|game| game.possible_with(&BAG))
+                .map(|game| u128::from(game.id)),
+        );
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<u128> {
+        return checked_sum(
+            games.iter().map(|x| x.minimum().power()),
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+use super::{Color, CubeSet, Game, Reveal};
+use crate::{AocError, Result};
+
+/// Read a bag from a TOML file of counts by color,
+///
+/// ```toml
+/// red = 12
+/// green = 13
+/// blue = 14
+/// ```
+///
+/// Colors left out aren't in the bag at all.
+pub fn load_bag(path: &Path) -> Result<CubeSet> {
+    let bad_file = |reason: String| AocError::BadFile {
+        path: path.to_path_buf(),
+        reason,
+    };
+    let table: toml::Table = fs::read_to_string(path)?
+        .parse()
+        .map_err(|e: toml::de::Error| {
+            bad_file(e.message().to_string())
+        })?;
+    let mut bag = CubeSet::default();
+    for (name, count) in table.iter() {
+        let color: Color = name.parse().map_err(bad_file)?;
+        let count = count
+            .as_integer()
+            .and_then(|x| u32::try_from(x).ok())
+            .ok_or_else(|| {
+                bad_file(format!("{} should be a count", name))
+            })?;
+        bag = bag.with(color, count);
+    }
+    return Ok(bag);
+}
+
+/// How one game went against a bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameReport {
+    pub id: u32,
+    /// The smallest bag the game could have come from.
+    pub minimum: CubeSet,
+    /// The first reveal the bag couldn't have made, and its index.
+    pub violation: Option<(usize, Reveal)>,
+}
+
+impl GameReport {
+    pub fn new(game: &Game, bag: &CubeSet) -> Self {
+        let violation = game
+            .reveals
+            .iter()
+            .enumerate()
+            .find(|(_, reveal)| !reveal.fits_within(bag))
+            .map(|(i, reveal)| (i, *reveal));
+        return GameReport {
+            id: game.id,
+            minimum: game.minimum(),
+            violation,
+        };
+    }
+
+    pub fn possible(&self) -> bool {
+        return self.violation.is_none();
+    }
+}
+
+/// Every game against one bag, with totals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BagReport {
+    pub bag: CubeSet,
+    pub games: Vec<GameReport>,
+}
+
+impl BagReport {
+    pub fn new(games: &[Game], bag: &CubeSet) -> Self {
+        return BagReport {
+            bag: *bag,
+            games: games
+                .iter()
+                .map(|x| GameReport::new(x, bag))
+                .collect(),
+        };
+    }
+
+    /// Games the bag could have played, what part 1 adds up.
+    pub fn possible(&self) -> impl Iterator<Item = &GameReport> {
+        return self.games.iter().filter(|x| x.possible());
+    }
+
+    pub fn possible_id_sum(&self) -> u128 {
+        return self.possible().map(|x| u128::from(x.id)).sum();
+    }
+
+    /// What part 2 adds up.
+    pub fn power_sum(&self) -> u128 {
+        return self
+            .games
+            .iter()
+            .map(|x| x.minimum.power())
+            .sum();
+    }
+
+    /// How many games have each minimal power.
+    pub fn power_histogram(&self) -> BTreeMap<u128, usize> {
+        let mut histogram = BTreeMap::new();
+        for game in self.games.iter() {
+            *histogram
+                .entry(game.minimum.power())
+                .or_insert(0) += 1;
+        }
+        return histogram;
+    }
+}
+
+impl Display for BagReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Bag: {}", self.bag)?;
+        for game in self.games.iter() {
+            write!(
+                f,
+                "Game {}: needs {} (power {}), ",
+                game.id,
+                game.minimum,
+                game.minimum.power()
+            )?;
+            match &game.violation {
+                None => writeln!(f, "possible")?,
+                Some((i, reveal)) => writeln!(
+                    f,
+                    "reveal {} shows {}",
+                    i + 1,
+                    reveal
+                )?,
+            }
+        }
+        writeln!(
+            f,
+            "Possible: {} of {} games, IDs add up to {}",
+            self.possible().count(),
+            self.games.len(),
+            self.possible_id_sum()
+        )?;
+        writeln!(
+            f,
+            "Minimal powers add up to {}",
+            self.power_sum()
+        )?;
+        write!(f, "Minimal powers:")?;
+        for (power, count) in self.power_histogram() {
+            write!(f, "\n  {:>8}  {}", power, count)?;
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use crate::answer::{checked_product, checked_sum};
+use crate::geometry::Point;
+use crate::grid::{Grid, Span};
+use crate::input::Line;
+use crate::{Input, Result, Solution};
+
+pub struct Day03;
+
+fn is_symbol(cell: &char) -> bool {
+    return !cell.is_ascii_digit() && *cell != '.';
+}
+
+/// Every number in the grid, with where it is.
+fn get_numbers(grid: &Grid<char>) -> Result<Vec<(Span, i64)>> {
+    let mut numbers = Vec::new();
+    for span in grid.spans(|x| x.is_ascii_digit()) {
+        // a digit run too long for an i64 is reported against its row
+        let row = span.start.y as usize;
+        let line = Line {
+            number: row + 1,
+            text: grid.row(row).iter().collect(),
+        };
+        numbers.push((
+            span,
+            line.parse_int(&grid.span_text(&span))?,
+        ));
+    }
+    return Ok(numbers);
+}
+
+/// The numbers touching each gear.
+fn get_gears(
+    grid: &Grid<char>,
+) -> Result<HashMap<Point, Vec<i64>>> {
+    let mut gears: HashMap<Point, Vec<i64>> = HashMap::new();
+    for (span, number) in get_numbers(grid)? {
+        for gear in span.touching(grid, |x| *x == '*') {
+            gears.entry(gear).or_default().push(number);
+        }
+    }
+    return Ok(gears);
+}
+
+impl Solution for Day03 {
+    type Parsed = Grid<char>;
+    type Answer = Result<i64>;
+
+    fn parse(input: &Input) -> Result<Grid<char>> {
+        let grid = Grid::parse(input)?;
+        // fail here rather than in the parts
+        get_numbers(&grid)?;
+        return Ok(grid);
+    }
+
+    fn part1(grid: &Grid<char>) -> Result<i64> {
+        return checked_sum(
+            get_numbers(grid)?
+                .into_iter()
+                .filter(|(span, _)| {
+                    !span.touching(grid, is_symbol).is_empty()
+                })
+                .map(|(_, number)| number),
+        );
+    }
+
+    fn part2(grid: &Grid<char>) -> Result<i64> {
+        let ratios = get_gears(grid)?
+            .into_values()
+            .filter(|x| x.len() == 2)
+            .map(checked_product)
+            .collect::<Result<Vec<i64>>>()?;
+        return checked_sum(ratios);
+    }
+}
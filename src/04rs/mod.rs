@@ -0,0 +1,52 @@
+use num_bigint::BigUint;
+
+use crate::{AocError, Input, Result, Solution};
+
+mod scratchcard;
+
+pub use scratchcard::{Cascade, Overrun, Scratchcard};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<Scratchcard>;
+    // points double and copies snowball, so neither has a bound
+    type Answer = Result<BigUint>;
+
+    fn parse(input: &Input) -> Result<Vec<Scratchcard>> {
+        let mut cards: Vec<Scratchcard> = Vec::new();
+        for line in input.lines()? {
+            let line = line?;
+            if line.text.is_empty() {
+                continue;
+            }
+            let card = Scratchcard::parse(&line)?;
+            // copies are won by position, so the numbers have to
+            // follow on
+            let expected = cards.len() as u32 + 1;
+            if card.number != expected {
+                return Err(line.error(format!(
+                    "expected card {}",
+                    expected
+                )));
+            }
+            cards.push(card);
+        }
+        return Ok(cards);
+    }
+
+    fn part1(cards: &Vec<Scratchcard>) -> Result<BigUint> {
+        return Ok(cards.iter().map(|x| x.points()).sum());
+    }
+
+    fn part2(cards: &Vec<Scratchcard>) -> Result<BigUint> {
+        let cascade = Cascade::new(cards);
+        if let Some(overrun) = cascade.overruns().first() {
+            return Err(AocError::Inconsistent(format!(
+                "card {}'s matches run {} past the last card",
+                overrun.card, overrun.past
+            )));
+        }
+        return Ok(cascade.total());
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use num_bigint::BigUint;
+
+use crate::input::Line;
+use crate::Result;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scratchcard {
+    pub number: u32,
+    pub winning: Vec<u32>,
+    pub numbers: Vec<u32>,
+}
+
+impl Scratchcard {
+    /// Parse `Card 1: 41 48 83 | 83 86  6 31`.
+    pub fn parse(line: &Line) -> Result<Scratchcard> {
+        let (number, winning, numbers) = line
+            .text
+            .strip_prefix("Card")
+            .and_then(|x| x.split_once(':'))
+            .and_then(|(number, rest)| {
+                let (winning, numbers) =
+                    rest.split_once(" | ")?;
+                Some((number, winning, numbers))
+            })
+            .ok_or_else(|| {
+                line.error("expected \"Card N: ... | ...\"")
+            })?;
+        return Ok(Scratchcard {
+            number: line.parse_int(number.trim())?,
+            winning: line.parse_ints(winning)?,
+            numbers: line.parse_ints(numbers)?,
+        });
+    }
+
+    /// How many of our numbers are winning ones.
+    pub fn matches(&self) -> usize {
+        let winning: HashSet<&u32> =
+            self.winning.iter().collect();
+        return self
+            .numbers
+            .iter()
+            .filter(|x| winning.contains(x))
+            .count();
+    }
+
+    /// One point for the first match, doubled for each one after.
+    pub fn points(&self) -> BigUint {
+        return match self.matches() {
+            0 => BigUint::default(),
+            n => BigUint::from(1u8) << (n - 1),
+        };
+    }
+}
+
+/// A card whose matches would copy cards after the last one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Overrun {
+    pub card: u32,
+    /// How many of the won cards don't exist.
+    pub past: usize,
+}
+
+/// Every card's copies once all the winning is done. Each copy of a
+/// card with `n` matches wins one more of each of the next `n`
+/// cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    numbers: Vec<u32>,
+    /// Copies of each card, originals included, in card order.
+    copies: Vec<BigUint>,
+    /// For each card, the cards whose matches copied it.
+    sources: Vec<Vec<usize>>,
+    overruns: Vec<Overrun>,
+}
+
+impl Cascade {
+    pub fn new(cards: &[Scratchcard]) -> Self {
+        let mut copies = vec![BigUint::from(1u8); cards.len()];
+        let mut sources = vec![Vec::new(); cards.len()];
+        let mut overruns = Vec::new();
+        for (i, card) in cards.iter().enumerate() {
+            let matches = card.matches();
+            let last = cards.len().min(i + 1 + matches);
+            if i + 1 + matches > last {
+                overruns.push(Overrun {
+                    card: card.number,
+                    past: i + 1 + matches - last,
+                });
+            }
+            // later cards only ever get copies from earlier ones, so
+            // this card's count is final by now
+            let won = copies[i].clone();
+            for j in i + 1..last {
+                copies[j] += &won;
+                sources[j].push(i);
+            }
+        }
+        return Cascade {
+            numbers: cards.iter().map(|x| x.number).collect(),
+            copies,
+            sources,
+            overruns,
+        };
+    }
+
+    fn index(&self, card: u32) -> Option<usize> {
+        return self.numbers.iter().position(|x| *x == card);
+    }
+
+    /// How many of `card` we end up with, the original included.
+    pub fn copies(&self, card: u32) -> Option<&BigUint> {
+        return self.index(card).map(|i| &self.copies[i]);
+    }
+
+    /// The cards that won copies of `card`, and how many each won.
+    pub fn sources(&self, card: u32) -> Vec<(u32, BigUint)> {
+        let Some(i) = self.index(card) else {
+            return Vec::new();
+        };
+        return self.sources[i]
+            .iter()
+            .map(|x| (self.numbers[*x], self.copies[*x].clone()))
+            .collect();
+    }
+
+    /// Every card we end up with.
+    pub fn total(&self) -> BigUint {
+        return self.copies.iter().sum();
+    }
+
+    /// Cards that won copies of cards past the last one, which the
+    /// puzzle promises never happens.
+    pub fn overruns(&self) -> &[Overrun] {
+        return &self.overruns;
+    }
+}
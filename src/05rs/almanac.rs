@@ -0,0 +1,143 @@
+use std::collections::{BTreeMap, HashSet};
+
+use itertools::Itertools;
+use regex::Regex;
+
+use super::{compose, GardenMap};
+use crate::{AocError, Input, Result};
+
+/// The seeds, and every `X-to-Y map:` keyed by the category it maps
+/// from, so categories form a chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    maps: BTreeMap<String, (String, GardenMap)>,
+}
+
+impl Almanac {
+    pub fn parse(input: &Input) -> Result<Self> {
+        let header =
+            Regex::new(r"^(?P<from>\w+)-to-(?P<to>\w+) map:$")
+                .unwrap();
+
+        let mut seeds = Vec::new();
+        let mut maps: BTreeMap<String, (String, GardenMap)> =
+            BTreeMap::new();
+        let mut current: Option<String> = None;
+        for line in input.lines()? {
+            let line = line?;
+            if line.text.is_empty() {
+                continue;
+            } else if let Some(numbers) =
+                line.text.strip_prefix("seeds:")
+            {
+                let numbers = line.parse_ints::<i64>(numbers)?;
+                // part 2 reads them as (start, length) pairs
+                for (start, length) in numbers.iter().tuples() {
+                    if start.checked_add(*length).is_none() {
+                        return Err(line.error(format!(
+                            "the seed range from {} runs past {}",
+                            start,
+                            i64::MAX
+                        )));
+                    }
+                }
+                seeds.extend(numbers);
+            } else if line.text.ends_with(" map:") {
+                let caps = line.captures(&header)?;
+                let from = line.capture(&caps, "from")?;
+                let to = line.capture(&caps, "to")?;
+                if maps.contains_key(from) {
+                    return Err(line.error(format!(
+                        "a second map from {}",
+                        from
+                    )));
+                }
+                maps.insert(
+                    from.to_string(),
+                    (to.to_string(), GardenMap::identity()),
+                );
+                current = Some(from.to_string());
+            } else {
+                let Some(from) = &current else {
+                    return Err(
+                        line.error("range before any map")
+                    );
+                };
+                let (destination, source, length) = line
+                    .parse_ints::<i64>(&line.text)?
+                    .into_iter()
+                    .collect_tuple()
+                    .ok_or_else(|| {
+                        line.error("expected 3 numbers per range")
+                    })?;
+                let ends = source
+                    .checked_add(length)
+                    .zip(destination.checked_add(length));
+                let Some((source_end, _)) = ends else {
+                    return Err(line.error(format!(
+                        "a range of length {} runs past {}",
+                        length,
+                        i64::MAX
+                    )));
+                };
+                let (_, map) = maps.get_mut(from).unwrap();
+                map.insert(source..source_end, destination)
+                    .map_err(|range| {
+                        line.error(format!(
+                            "overlaps the range {:?}",
+                            range
+                        ))
+                    })?;
+            }
+        }
+        return Ok(Almanac { seeds, maps });
+    }
+
+    /// The category `from` maps to, and the map.
+    pub fn map(&self, from: &str) -> Option<(&str, &GardenMap)> {
+        return self
+            .maps
+            .get(from)
+            .map(|(to, map)| (to.as_str(), map));
+    }
+
+    /// The maps to follow to get from `from` to `to`, in order.
+    pub fn chain(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<&GardenMap>> {
+        let mut chain = Vec::new();
+        let mut seen = HashSet::new();
+        let mut category = from;
+        while category != to {
+            if !seen.insert(category) {
+                return Err(AocError::Inconsistent(format!(
+                    "maps from {} loop back to {} before reaching {}",
+                    from, category, to
+                )));
+            }
+            let Some((next, map)) = self.map(category) else {
+                return Err(AocError::Inconsistent(format!(
+                    "no map from {}, so {} never reaches {}",
+                    category, from, to
+                )));
+            };
+            chain.push(map);
+            category = next;
+        }
+        return Ok(chain);
+    }
+
+    /// A single map from category `from` to category `to`.
+    pub fn resolve(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<GardenMap> {
+        let chain: Vec<GardenMap> =
+            self.chain(from, to)?.into_iter().cloned().collect();
+        return Ok(compose(&chain));
+    }
+}
@@ -0,0 +1,91 @@
+use itertools::Itertools;
+
+use crate::parallel;
+use crate::ranges::{PiecewiseMap, RangeSet};
+use crate::{AocError, Input, Result, Solution};
+
+mod almanac;
+
+pub use almanac::Almanac;
+
+/// An almanac map: some ranges move, everything else stays put.
+pub type GardenMap = PiecewiseMap<i64>;
+
+pub struct Day05;
+
+/// The seeds, and the single map taking each one to its location.
+#[derive(Debug, Clone)]
+pub struct Garden {
+    pub seeds: Vec<i64>,
+    pub seed_to_location: GardenMap,
+}
+
+/// Every stage, one after the other, as a single map.
+pub fn compose(maps: &[GardenMap]) -> GardenMap {
+    return maps
+        .iter()
+        .fold(GardenMap::identity(), |map, next| map.then(next));
+}
+
+impl Solution for Day05 {
+    type Parsed = Garden;
+    type Answer = i64;
+
+    fn parse(input: &Input) -> Result<Garden> {
+        let almanac = Almanac::parse(input)?;
+        let seed_to_location =
+            almanac.resolve("seed", "location")?;
+        if almanac.seeds.is_empty() {
+            return Err(AocError::Inconsistent(
+                "there are no seeds".to_string(),
+            ));
+        }
+        // part 2 reads the seeds as (start, length) pairs
+        if almanac.seeds.len() % 2 != 0 {
+            return Err(AocError::Inconsistent(format!(
+                "{} seed numbers can't all pair up into ranges",
+                almanac.seeds.len()
+            )));
+        }
+        for (start, length) in almanac.seeds.iter().tuples() {
+            if *length <= 0 {
+                return Err(AocError::Inconsistent(format!(
+                    "the seed range from {} has length {}",
+                    start, length
+                )));
+            }
+        }
+        return Ok(Garden {
+            seeds: almanac.seeds,
+            seed_to_location,
+        });
+    }
+
+    fn part1(garden: &Garden) -> i64 {
+        return garden
+            .seeds
+            .iter()
+            .map(|seed| garden.seed_to_location.map(*seed))
+            .min()
+            .unwrap();
+    }
+
+    fn part2(garden: &Garden) -> i64 {
+        // seed numbers come in (start, length) pairs; expanding each
+        // range into its seeds would OOM, so map whole ranges instead
+        let seed_ranges: RangeSet<i64> = garden
+            .seeds
+            .iter()
+            .tuples()
+            .map(|(start, range_len)| *start..(start + range_len))
+            .collect();
+
+        // each range is independent, so they can run concurrently
+        let lowest =
+            parallel::map(seed_ranges.ranges(), |range| {
+                let seeds = [range.clone()].into_iter().collect();
+                garden.seed_to_location.lowest(&seeds)
+            });
+        return lowest.into_iter().flatten().min().unwrap();
+    }
+}
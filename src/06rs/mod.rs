@@ -0,0 +1,146 @@
+use std::ops::RangeInclusive;
+
+use num::integer::Roots;
+use num::Integer;
+use num_bigint::BigUint;
+
+use crate::input::Line;
+use crate::{AocError, Input, Result, Solution};
+
+pub struct Day06;
+
+/// The hold times that beat `record` in a race lasting `time`, if
+/// any. Holding for `t` goes `t * (time - t)`, so these lie strictly
+/// between the roots of `t² - time·t + record`, which we find with
+/// an integer square root and then nudge onto the exact bounds.
+/// `T` needs room for `time²`; `BigUint` always has it.
+pub fn winning_holds<T>(
+    time: &T,
+    record: &T,
+) -> Option<RangeInclusive<T>>
+where
+    T: Integer + Roots + Clone + From<u8>,
+{
+    let two = T::from(2);
+    let beats = |hold: &T| {
+        hold.clone() * (time.clone() - hold.clone()) > *record
+    };
+
+    let square = time.clone() * time.clone();
+    let four_record = T::from(4) * record.clone();
+    if square < four_record {
+        return None;
+    }
+    let root = (square - four_record).sqrt();
+    // the root is floored, so this lands at most a step or two
+    // from the first winning hold, on either side
+    let mut low = (time.clone() - root) / two.clone();
+    while low > T::zero() && beats(&(low.clone() - T::one())) {
+        low = low - T::one();
+    }
+    let middle = time.clone() / two;
+    while !beats(&low) {
+        if low >= middle {
+            // not even the best hold wins
+            return None;
+        }
+        low = low + T::one();
+    }
+    // the distances are symmetric around the middle
+    let high = time.clone() - low.clone();
+    return Some(low..=high);
+}
+
+/// How many hold times beat `record`.
+pub fn winning_ways<T>(time: &T, record: &T) -> T
+where
+    T: Integer + Roots + Clone + From<u8>,
+{
+    return match winning_holds(time, record) {
+        Some(holds) => {
+            let (low, high) = holds.into_inner();
+            high - low + T::one()
+        }
+        None => T::zero(),
+    };
+}
+
+/// Every race's time and record, and the one long race they make
+/// once the bad kerning is taken out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Races {
+    pub races: Vec<(u64, u64)>,
+    pub long_race: (BigUint, BigUint),
+}
+
+/// The numbers on a row, and all their digits as one number.
+fn parse_row(
+    line: Option<Result<Line>>,
+    number: usize,
+    label: &str,
+) -> Result<(Vec<u64>, BigUint)> {
+    let line = match line {
+        Some(line) => line?,
+        None => {
+            return Err(AocError::Missing {
+                line: number,
+                content: String::new(),
+                field: label.to_string(),
+            });
+        }
+    };
+    let values = line
+        .text
+        .strip_prefix(label)
+        .and_then(|x| x.strip_prefix(':'))
+        .ok_or_else(|| line.missing(label))?;
+
+    let numbers = line.parse_ints::<u64>(values)?;
+    if numbers.is_empty() {
+        return Err(line.error("no numbers"));
+    }
+    let digits: String = values.split_whitespace().collect();
+    return Ok((numbers, line.parse_int(&digits)?));
+}
+
+impl Solution for Day06 {
+    type Parsed = Races;
+    // one long race can need more than 64 bits
+    type Answer = BigUint;
+
+    fn parse(input: &Input) -> Result<Races> {
+        let mut lines = input.lines()?;
+        let (times, time) = parse_row(lines.next(), 1, "Time")?;
+        let (distances, distance) =
+            parse_row(lines.next(), 2, "Distance")?;
+        if times.len() != distances.len() {
+            return Err(AocError::Inconsistent(format!(
+                "{} times but {} distances",
+                times.len(),
+                distances.len()
+            )));
+        }
+        return Ok(Races {
+            races: times.into_iter().zip(distances).collect(),
+            long_race: (time, distance),
+        });
+    }
+
+    fn part1(races: &Races) -> BigUint {
+        let mut total = BigUint::from(1u8);
+        for (time, distance) in races.races.iter() {
+            // wide enough for any u64 time squared
+            total *= winning_ways::<u128>(
+                &u128::from(*time),
+                &u128::from(*distance),
+            );
+        }
+        return total;
+    }
+
+    fn part2(races: &Races) -> BigUint {
+        // the spaces are bad kerning, it's one big race
+        let (time, distance) = &races.long_race;
+        return winning_ways(time, distance);
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::answer::checked_sum;
+use crate::{Input, Result, Solution};
+
+pub struct Day07;
+
+fn get_card_map(jokers: bool) -> HashMap<char, char> {
+    return HashMap::from([
+        // jokers are the weakest card, jacks sit between T and Q
+        ('J', if jokers { '_' } else { 'j' }),
+        ('2', 'a'),
+        ('3', 'b'),
+        ('4', 'c'),
+        ('5', 'd'),
+        ('6', 'e'),
+        ('7', 'f'),
+        ('8', 'g'),
+        ('9', 'h'),
+        ('T', 'i'),
+        ('Q', 'k'),
+        ('K', 'l'),
+        ('A', 'm'),
+    ]);
+}
+
+fn normalize_hand(
+    hand: &str,
+    card_map: &HashMap<char, char>,
+) -> String {
+    return hand.chars().map(|c| card_map[&c]).collect();
+}
+
+fn get_hand_score(cards: &str) -> i32 {
+    let counts = cards.chars().counts();
+    if counts.len() == 1 {
+        // 5 of a kind
+        return 6;
+    } else if counts.len() == 2 {
+        if counts.values().any(|&x| x == 4) {
+            // 4 of a kind
+            return 5;
+        } else {
+            // full house
+            return 4;
+        }
+    } else if counts.len() == 3 {
+        if counts.values().any(|&x| x == 3) {
+            // 3 of a kind
+            return 3;
+        } else {
+            // two pairs
+            return 2;
+        }
+    } else if counts.len() == 4 {
+        // one pair
+        return 1;
+    } else {
+        // high card
+        return 0;
+    }
+}
+
+fn get_hand_score_2(cards: &str) -> i32 {
+    let counts = cards.chars().counts();
+    let joker_count = counts.get(&'_').unwrap_or(&0).to_owned();
+    if counts.len() == 1 || (counts.len() == 2 && joker_count > 0)
+    {
+        // 5 of a kind
+        return 6;
+    } else if counts.len() == 2
+        || (counts.len() == 3 && joker_count > 0)
+    {
+        if counts.values().any(|&x| x >= (4 - joker_count)) {
+            // 4 of a kind
+            return 5;
+        } else {
+            // full house
+            return 4;
+        }
+    } else if counts.len() == 3
+        || (counts.len() == 4 && joker_count > 0)
+    {
+        if counts.values().any(|&x| x >= (3 - joker_count)) {
+            // 3 of a kind
+            return 3;
+        } else {
+            // two pairs
+            return 2;
+        }
+    } else if counts.len() == 4
+        || (counts.len() == 5 && joker_count > 0)
+    {
+        // one pair
+        return 1;
+    } else {
+        // high card
+        return 0;
+    }
+}
+
+fn get_hands_ranked(
+    hands: &[(String, i32)],
+    jokers: bool,
+) -> Vec<(String, i32, i32)> {
+    let card_map = get_card_map(jokers);
+    let get_score = if jokers {
+        get_hand_score_2
+    } else {
+        get_hand_score
+    };
+
+    let mut hands_ranked: Vec<(String, i32)> = hands
+        .iter()
+        .map(|(hand, bid)| {
+            (normalize_hand(hand, &card_map), *bid)
+        })
+        .collect_vec();
+    hands_ranked.sort_by(|x, y| {
+        let x_hand_score = get_score(&x.0);
+        let y_hand_score = get_score(&y.0);
+
+        if x_hand_score == y_hand_score {
+            return x.0.cmp(&y.0);
+        } else {
+            return x_hand_score.cmp(&y_hand_score);
+        }
+    });
+    return hands_ranked
+        .iter()
+        .enumerate()
+        .map(|x| (x.1 .0.clone(), x.1 .1, (x.0 as i32 + 1)))
+        .collect_vec();
+}
+
+fn get_total_winnings(
+    hands: &[(String, i32)],
+    jokers: bool,
+) -> Result<i64> {
+    // a bid times a rank always fits, the sum might not
+    return checked_sum(
+        get_hands_ranked(hands, jokers).iter().map(
+            |(_, bid, rank)| i64::from(*bid) * i64::from(*rank),
+        ),
+    );
+}
+
+impl Solution for Day07 {
+    type Parsed = Vec<(String, i32)>;
+    type Answer = Result<i64>;
+
+    fn parse(input: &Input) -> Result<Vec<(String, i32)>> {
+        let card_map = get_card_map(false);
+
+        let mut hands: Vec<(String, i32)> = Vec::new();
+        for line in input.lines()? {
+            let line = line?;
+            if line.text.is_empty() {
+                continue;
+            }
+            let (hand, bid) = line
+                .text
+                .split(' ')
+                .collect_tuple()
+                .ok_or_else(|| line.missing("bid"))?;
+            if hand.len() != 5
+                || !hand
+                    .chars()
+                    .all(|c| card_map.contains_key(&c))
+            {
+                return Err(line.error("not a hand of 5 cards"));
+            }
+
+            hands.push((hand.to_string(), line.parse_int(bid)?));
+        }
+        return Ok(hands);
+    }
+
+    fn part1(hands: &Vec<(String, i32)>) -> Result<i64> {
+        return get_total_winnings(hands, false);
+    }
+
+    fn part2(hands: &Vec<(String, i32)>) -> Result<i64> {
+        return get_total_winnings(hands, true);
+    }
+}
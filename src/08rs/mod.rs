@@ -0,0 +1,281 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{AocError, Input, Result, Solution};
+
+pub struct Day08;
+
+type Network = HashMap<String, (String, String)>;
+
+fn get_steps_to_zzz(
+    instructions: &[char],
+    network: &Network,
+) -> Result<i64> {
+    if !network.contains_key("AAA") {
+        return Err(AocError::Inconsistent(
+            "there is no AAA node to start from".to_string(),
+        ));
+    }
+    let mut current_node: String = "AAA".to_string();
+    let mut current_instruction: usize = 0;
+    let mut step_count = 0;
+    // the same node at the same instruction means we're going round
+    let mut seen: HashSet<(String, usize)> = HashSet::new();
+    loop {
+        if !seen
+            .insert((current_node.clone(), current_instruction))
+        {
+            return Err(AocError::Inconsistent(
+                "AAA goes round in circles without reaching ZZZ"
+                    .to_string(),
+            ));
+        }
+        let next_node;
+        if instructions[current_instruction] == 'L' {
+            next_node = network[&current_node].0.clone();
+        } else {
+            next_node = network[&current_node].1.clone();
+        }
+
+        step_count += 1;
+        current_instruction += 1;
+        if current_instruction == instructions.len() {
+            current_instruction = 0;
+        }
+        current_node = next_node.clone();
+
+        if current_node == "ZZZ" {
+            break;
+        }
+    }
+    return Ok(step_count);
+}
+
+#[derive(Debug)]
+struct NodeTrack {
+    visited: Vec<(String, String)>,
+    end_distances: Vec<i64>,
+    frozen_loop: bool,
+}
+
+fn get_steps_to_end(
+    instructions: &[char],
+    network: &Network,
+) -> Result<i64> {
+    let mut node_tracks: Vec<NodeTrack> = network
+        .keys()
+        .filter(|x| x.ends_with('A'))
+        .map(|x| NodeTrack {
+            // store the path of each track, identified by its starting node;
+            // includes the full instruction sequence that was taken to get to
+            // each node
+            visited: vec![(
+                instructions[0].to_string(),
+                x.clone(),
+            )],
+            // how many steps until an end state was found
+            end_distances: Vec::new(),
+            // the track looped around once, we no longer need to keep track
+            // of its visited path (since it will cycle)
+            frozen_loop: false,
+        })
+        .collect_vec();
+    if node_tracks.is_empty() {
+        return Err(AocError::Inconsistent(
+            "there are no nodes ending in A to start from"
+                .to_string(),
+        ));
+    }
+
+    let mut current_instruction: usize = 0;
+    let mut step_count: i64 = 0;
+    loop {
+        if node_tracks.iter().all(|x| x.frozen_loop) {
+            break;
+        }
+
+        let next_instruction;
+        if current_instruction + 1 == instructions.len() {
+            next_instruction = 0;
+        } else {
+            next_instruction = current_instruction + 1;
+        }
+        let current_instruction_char =
+            instructions[current_instruction];
+
+        // loop into all tracks have cycled
+        for track in
+            node_tracks.iter_mut().filter(|nt| !nt.frozen_loop)
+        {
+            let current_node =
+                track.visited.last().unwrap().1.clone();
+
+            let next_node;
+            if current_instruction_char == 'L' {
+                next_node =
+                    network.get(&current_node).unwrap().0.clone();
+            } else {
+                next_node =
+                    network.get(&current_node).unwrap().1.clone();
+            }
+
+            let next_instruct_node = (
+                instructions[..=next_instruction]
+                    .iter()
+                    .collect(),
+                next_node.clone(),
+            );
+            let maybe_visited_item = track
+                .visited
+                .binary_search_by(|probe| {
+                    probe.cmp(&next_instruct_node)
+                })
+                .ok();
+            if let Some(visited_item_index) = maybe_visited_item {
+                // track has cycled
+                let cycle_len =
+                    track.visited.len() - visited_item_index;
+                if !track
+                    .end_distances
+                    .iter()
+                    .any(|x| *x >= visited_item_index as i64)
+                {
+                    return Err(AocError::Inconsistent(format!(
+                        "{} goes round in circles without reaching a node ending in Z",
+                        track.visited[0].1
+                    )));
+                }
+                track.frozen_loop = true;
+
+                // end distances are now computed by distance between each other
+                let mut new_end_distances = Vec::new();
+                let mut last_step = 0;
+                let mut first_step = 0;
+                for end_distance in &track.end_distances {
+                    let dist_from_cycle_start = end_distance
+                        - (visited_item_index as i64);
+                    if dist_from_cycle_start < 0 {
+                        continue;
+                    }
+                    if first_step == 0 {
+                        first_step = dist_from_cycle_start;
+                        last_step = dist_from_cycle_start;
+                    } else {
+                        new_end_distances.push(
+                            dist_from_cycle_start - last_step,
+                        );
+                        last_step = dist_from_cycle_start;
+                    }
+                }
+                new_end_distances.push(
+                    (cycle_len as i64) - last_step + first_step,
+                );
+                track.end_distances = new_end_distances;
+            } else {
+                // track continues
+                track.visited.push(next_instruct_node);
+
+                if next_node.ends_with('Z') {
+                    track.end_distances.push(step_count + 1);
+                }
+            }
+        }
+
+        step_count += 1;
+        current_instruction = next_instruction;
+    }
+
+    // use LCM to compute the first step where all node tracks have reached an end;
+    // I cheated and looked at the end states, there is only ever one per track,
+    // but this could be pretty easily updated to cartesian product the different
+    // end distances of all tracks to do LCM on
+    return Ok(node_tracks
+        .iter()
+        .map(|x| x.end_distances[0])
+        .reduce(num::integer::lcm)
+        .unwrap());
+}
+
+impl Solution for Day08 {
+    type Parsed = (Vec<char>, Network);
+    type Answer = Result<i64>;
+
+    fn parse(input: &Input) -> Result<(Vec<char>, Network)> {
+        let mut lines = input.lines()?;
+        let instructions_line = match lines.next() {
+            Some(line) => line?,
+            None => {
+                return Err(AocError::Missing {
+                    line: 1,
+                    content: String::new(),
+                    field: "instructions".to_string(),
+                });
+            }
+        };
+        if instructions_line.text.is_empty()
+            || !instructions_line
+                .text
+                .chars()
+                .all(|c| c == 'L' || c == 'R')
+        {
+            return Err(instructions_line
+                .error("instructions must be L or R"));
+        }
+        let instructions =
+            instructions_line.text.chars().collect_vec();
+
+        let re = Regex::new(
+            r"(?P<node>[A-Z0-9]{3}) = \((?P<left>[A-Z0-9]{3}), (?P<right>[A-Z0-9]{3})\)"
+        ).unwrap();
+
+        let mut network = HashMap::new();
+        let mut node_lines = Vec::new();
+        for line in lines.skip(1) {
+            let line = line?;
+            if line.text.is_empty() {
+                continue;
+            }
+            let caps = line.captures(&re)?;
+            let node = line.capture(&caps, "node")?.to_string();
+            if network.contains_key(&node) {
+                return Err(line.error(format!("{} again", node)));
+            }
+            network.insert(
+                node,
+                (
+                    line.capture(&caps, "left")?.to_string(),
+                    line.capture(&caps, "right")?.to_string(),
+                ),
+            );
+            node_lines.push(line);
+        }
+        // so walking the network can't step off it
+        for line in node_lines {
+            let caps = line.captures(&re)?;
+            for side in ["left", "right"] {
+                let next = line.capture(&caps, side)?;
+                if !network.contains_key(next) {
+                    return Err(line.error(format!(
+                        "unknown node {}",
+                        next
+                    )));
+                }
+            }
+        }
+        return Ok((instructions, network));
+    }
+
+    fn part1(
+        (instructions, network): &(Vec<char>, Network),
+    ) -> Result<i64> {
+        return get_steps_to_zzz(instructions, network);
+    }
+
+    fn part2(
+        (instructions, network): &(Vec<char>, Network),
+    ) -> Result<i64> {
+        return get_steps_to_end(instructions, network);
+    }
+}
@@ -1,37 +1,3 @@
-
-// part 1
-
-fn get_steps_to_end(
-    instructions: &Vec<char>,
-    network: &HashMap<String, (String, String)>,
-) -> i32 {
-    // part 1
-    let mut current_node: String = "AAA".to_string();
-    let mut current_instruction: usize = 0 as usize;
-    let mut step_count = 0;
-    loop {
-        let next_node;
-        if instructions[current_instruction as usize] == 'L' {
-            next_node = network[&current_node].0.clone();
-        } else {
-            next_node = network[&current_node].1.clone();
-        }
-
-        step_count += 1;
-        current_instruction += 1;
-        if current_instruction == instructions.len() {
-            current_instruction = 0;
-        }
-        current_node = next_node.clone();
-
-        if current_node == "ZZZ" {
-            break;
-        }
-    }
-    return step_count;
-}
-
-
 // naive approach to part 2, going one step at a time - this would
 // definitely take too long, never finished running
 
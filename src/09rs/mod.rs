@@ -0,0 +1,84 @@
+use itertools::Itertools;
+
+use crate::answer::checked_sum;
+use crate::{AocError, Input, Result, Solution};
+
+pub struct Day09;
+
+fn overflow(x: i64, y: i64) -> AocError {
+    return AocError::Overflow(format!(
+        "{} - {} doesn't fit in i64",
+        x, y
+    ));
+}
+
+fn get_next_sequence_value(
+    history: &[i64],
+    prev: bool,
+) -> Result<i64> {
+    let mut sequences: Vec<Vec<i64>> = vec![history.to_vec()];
+    let mut current_sequence: Vec<i64> = history.to_vec();
+    loop {
+        if current_sequence.iter().all_equal() {
+            break;
+        }
+        let next_sequence = current_sequence
+            .iter()
+            .tuple_windows()
+            .map(|(x, y)| {
+                y.checked_sub(*x).ok_or_else(|| overflow(*y, *x))
+            })
+            .collect::<Result<Vec<i64>>>()?;
+
+        sequences.push(next_sequence.clone());
+
+        current_sequence = next_sequence;
+    }
+
+    if prev {
+        return sequences
+            .iter()
+            .rev()
+            .map(|s| *s.first().unwrap())
+            .try_fold(0, |acc, x| {
+                x.checked_sub(acc).ok_or_else(|| overflow(x, acc))
+            });
+    } else {
+        return checked_sum(
+            sequences.iter().rev().map(|s| *s.last().unwrap()),
+        );
+    }
+}
+
+impl Solution for Day09 {
+    type Parsed = Vec<Vec<i64>>;
+    type Answer = Result<i64>;
+
+    fn parse(input: &Input) -> Result<Vec<Vec<i64>>> {
+        let mut histories: Vec<Vec<i64>> = Vec::new();
+        for line in input.lines()? {
+            let line = line?;
+            if line.text.is_empty() {
+                continue;
+            }
+            histories.push(line.parse_ints(&line.text)?);
+        }
+        return Ok(histories);
+    }
+
+    fn part1(histories: &Vec<Vec<i64>>) -> Result<i64> {
+        let values = histories
+            .iter()
+            .map(|h| get_next_sequence_value(h, false))
+            .collect::<Result<Vec<i64>>>()?;
+        return checked_sum(values);
+    }
+
+    fn part2(histories: &Vec<Vec<i64>>) -> Result<i64> {
+        let values = histories
+            .iter()
+            .map(|h| get_next_sequence_value(h, true))
+            .collect::<Result<Vec<i64>>>()?;
+        return checked_sum(values);
+    }
+}
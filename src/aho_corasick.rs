@@ -0,0 +1,198 @@
+use std::collections::VecDeque;
+
+use itertools::Either;
+
+/// A transition that isn't in the trie yet, while building.
+const NONE: usize = usize::MAX;
+
+/// Where a pattern was found, as a byte range, and its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match<'a, V> {
+    pub start: usize,
+    pub end: usize,
+    pub value: &'a V,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    /// The node after each byte. Once linked, bytes that leave the
+    /// trie already point where the fail links would lead, so
+    /// matching is one lookup per byte.
+    next: Box<[usize; 256]>,
+    /// The node for the longest proper suffix that's also in the
+    /// trie.
+    fail: usize,
+    /// Patterns ending here, longest first, including the ones
+    /// reached through `fail`.
+    outputs: Vec<usize>,
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        return Node {
+            next: Box::new([NONE; 256]),
+            fail: 0,
+            outputs: Vec::new(),
+        };
+    }
+}
+
+/// Finds every occurrence of many patterns in one pass over the
+/// text, overlapping ones included.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick<V> {
+    nodes: Vec<Node>,
+    /// Each pattern's length in bytes, and its value.
+    patterns: Vec<(usize, V)>,
+    /// Reads text from the end back, see `reversed`.
+    reversed: bool,
+}
+
+impl<V> Default for AhoCorasick<V> {
+    fn default() -> Self {
+        return AhoCorasick::new(std::iter::empty::<(&str, V)>());
+    }
+}
+
+impl<V> AhoCorasick<V> {
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = (P, V)>,
+        P: AsRef<str>,
+    {
+        return AhoCorasick::build(patterns, false);
+    }
+
+    /// An automaton that reads text from the end back, so matches
+    /// come by where they start, last first.
+    pub fn reversed<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = (P, V)>,
+        P: AsRef<str>,
+    {
+        return AhoCorasick::build(patterns, true);
+    }
+
+    fn build<I, P>(patterns: I, reversed: bool) -> Self
+    where
+        I: IntoIterator<Item = (P, V)>,
+        P: AsRef<str>,
+    {
+        let mut automaton = AhoCorasick {
+            nodes: vec![Node::default()],
+            patterns: Vec::new(),
+            reversed,
+        };
+        for (pattern, value) in patterns {
+            automaton.add(pattern.as_ref(), value);
+        }
+        automaton.link();
+        return automaton;
+    }
+
+    /// Put `pattern` in the trie. Empty patterns would match
+    /// everywhere, so they're skipped.
+    fn add(&mut self, pattern: &str, value: V) {
+        if pattern.is_empty() {
+            return;
+        }
+        let bytes;
+        if self.reversed {
+            bytes = Either::Right(pattern.bytes().rev());
+        } else {
+            bytes = Either::Left(pattern.bytes());
+        }
+        let mut node = 0;
+        for byte in bytes {
+            let byte = byte as usize;
+            if self.nodes[node].next[byte] == NONE {
+                self.nodes.push(Node::default());
+                self.nodes[node].next[byte] =
+                    self.nodes.len() - 1;
+            }
+            node = self.nodes[node].next[byte];
+        }
+        self.nodes[node].outputs.push(self.patterns.len());
+        self.patterns.push((pattern.len(), value));
+    }
+
+    /// Fill in fail links, outputs and the missing transitions
+    /// breadth first, so every shorter suffix is done before it's
+    /// needed.
+    fn link(&mut self) {
+        let mut queue = VecDeque::new();
+        for byte in 0..256 {
+            match self.nodes[0].next[byte] {
+                NONE => self.nodes[0].next[byte] = 0,
+                child => queue.push_back(child),
+            }
+        }
+        while let Some(node) = queue.pop_front() {
+            let fail = self.nodes[node].fail;
+            for byte in 0..256 {
+                let child = self.nodes[node].next[byte];
+                let via_fail = self.nodes[fail].next[byte];
+                if child == NONE {
+                    self.nodes[node].next[byte] = via_fail;
+                    continue;
+                }
+                self.nodes[child].fail = via_fail;
+                let inherited =
+                    self.nodes[via_fail].outputs.clone();
+                self.nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    /// The longest pattern, in bytes.
+    pub fn longest(&self) -> usize {
+        return self
+            .patterns
+            .iter()
+            .map(|x| x.0)
+            .max()
+            .unwrap_or(0);
+    }
+
+    /// Every match in `text`, by where it ends, longest first when
+    /// several end at the same place. A reversed automaton gives
+    /// them by where they start instead, last first.
+    pub fn find_iter<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> impl Iterator<Item = Match<'a, V>> + 'a {
+        let bytes;
+        if self.reversed {
+            bytes = Either::Right(text.bytes().rev());
+        } else {
+            bytes = Either::Left(text.bytes());
+        }
+        let mut node = 0;
+        return bytes.enumerate().flat_map(move |(i, byte)| {
+            node = self.nodes[node].next[byte as usize];
+            self.nodes[node].outputs.iter().map(move |pattern| {
+                let (len, value) = &self.patterns[*pattern];
+                // `i + 1` bytes read so far, from whichever end
+                let start;
+                if self.reversed {
+                    start = text.len() - (i + 1);
+                } else {
+                    start = i + 1 - len;
+                }
+                Match {
+                    start,
+                    end: start + len,
+                    value,
+                }
+            })
+        });
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Vec<Match<'a, V>> {
+        return self.find_iter(text).collect();
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::{self, Display};
+
+use num::traits::{CheckedAdd, CheckedMul};
+use num::{One, Zero};
+use num_bigint::{BigInt, BigUint};
+
+use crate::error::{AocError, Result};
+
+/// What a part comes up with: an integer of any size, or text for
+/// the puzzles that want a word or a picture.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(BigInt),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Anything a part can return. A `Result` lets a part fail, e.g. on
+/// overflow, instead of printing a wrapped number.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer>;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Result<Answer> {
+        return Ok(self);
+    }
+}
+
+impl<T: IntoAnswer> IntoAnswer for Result<T> {
+    fn into_answer(self) -> Result<Answer> {
+        return self?.into_answer();
+    }
+}
+
+macro_rules! int_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    return Answer::Int(BigInt::from(n));
+                }
+            }
+
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Result<Answer> {
+                    return Ok(Answer::from(self));
+                }
+            }
+        )*
+    };
+}
+
+int_answer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128,
+    usize, BigInt, BigUint
+);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        return Answer::Text(s);
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        return Answer::Text(s.to_string());
+    }
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Result<Answer> {
+        return Ok(Answer::from(self));
+    }
+}
+
+/// Add up `values`, failing instead of wrapping.
+pub fn checked_sum<T, I>(values: I) -> Result<T>
+where
+    T: CheckedAdd + Zero,
+    I: IntoIterator<Item = T>,
+{
+    let mut sum = T::zero();
+    for value in values {
+        sum = sum
+            .checked_add(&value)
+            .ok_or_else(|| overflow::<T>("sum"))?;
+    }
+    return Ok(sum);
+}
+
+/// Multiply `values` together, failing instead of wrapping.
+pub fn checked_product<T, I>(values: I) -> Result<T>
+where
+    T: CheckedMul + One,
+    I: IntoIterator<Item = T>,
+{
+    let mut product = T::one();
+    for value in values {
+        product = product
+            .checked_mul(&value)
+            .ok_or_else(|| overflow::<T>("product"))?;
+    }
+    return Ok(product);
+}
+
+fn overflow<T>(what: &str) -> AocError {
+    return AocError::Overflow(format!(
+        "{} doesn't fit in {}",
+        what,
+        std::any::type_name::<T>()
+    ));
+}
@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AocError, Result};
+use crate::solution::Part;
+
+const HEADER: &str =
+    "# Known-good answers, keyed by day and input name.\n";
+
+/// Known-good answers, stored in `answers.toml` as
+///
+/// ```toml
+/// [day05.in]
+/// part1 = "175622908"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers:
+        BTreeMap<u32, BTreeMap<String, BTreeMap<Part, String>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// The `answers.toml` at the root of the repo.
+pub fn default_path() -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("answers.toml");
+}
+
+fn bad_file(path: &Path, reason: String) -> AocError {
+    return AocError::BadFile {
+        path: path.to_path_buf(),
+        reason,
+    };
+}
+
+/// `name` as a toml key: bare if it can be, quoted otherwise, so
+/// names like `ex.small` stay one key.
+fn toml_key(name: &str) -> String {
+    let bare = !name.is_empty()
+        && name.chars().all(|c| {
+            c.is_ascii_alphanumeric() || c == '_' || c == '-'
+        });
+    if bare {
+        return name.to_string();
+    }
+    return toml::Value::String(name.to_string()).to_string();
+}
+
+impl Answers {
+    /// Load answers from `path`, a missing file has no answers yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut answers = Answers::default();
+        if !path.exists() {
+            return Ok(answers);
+        }
+
+        let table: toml::Table = fs::read_to_string(path)?
+            .parse()
+            .map_err(|e: toml::de::Error| {
+                bad_file(path, e.message().to_string())
+            })?;
+        for (day_key, inputs) in table.iter() {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|x| x.parse::<u32>().ok())
+                .ok_or_else(|| {
+                    bad_file(
+                        path,
+                        format!("bad day {:?}", day_key),
+                    )
+                })?;
+            let inputs = inputs.as_table().ok_or_else(|| {
+                bad_file(
+                    path,
+                    format!("{} is not a table", day_key),
+                )
+            })?;
+            for (name, parts) in inputs.iter() {
+                for part in Part::ALL {
+                    let key = format!("part{}", part);
+                    let Some(value) = parts.get(&key) else {
+                        continue;
+                    };
+                    let answer =
+                        value.as_str().ok_or_else(|| {
+                            bad_file(
+                                path,
+                                format!(
+                                    "{}.{}.{} is not a string",
+                                    day_key, name, key
+                                ),
+                            )
+                        })?;
+                    answers.set(day, name, part, answer);
+                }
+            }
+        }
+        return Ok(answers);
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut s = HEADER.to_string();
+        for (day, inputs) in self.answers.iter() {
+            for (name, parts) in inputs.iter() {
+                writeln!(
+                    s,
+                    "\n[day{:02}.{}]",
+                    day,
+                    toml_key(name)
+                )
+                .unwrap();
+                for (part, answer) in parts.iter() {
+                    let answer =
+                        toml::Value::String(answer.clone());
+                    writeln!(s, "part{} = {}", part, answer)
+                        .unwrap();
+                }
+            }
+        }
+        fs::write(path, s)?;
+        return Ok(());
+    }
+
+    pub fn get(
+        &self,
+        day: u32,
+        name: &str,
+        part: Part,
+    ) -> Option<&str> {
+        return self
+            .answers
+            .get(&day)
+            .and_then(|inputs| inputs.get(name))
+            .and_then(|parts| parts.get(&part))
+            .map(|x| x.as_str());
+    }
+
+    pub fn set(
+        &mut self,
+        day: u32,
+        name: &str,
+        part: Part,
+        answer: &str,
+    ) {
+        self.answers
+            .entry(day)
+            .or_default()
+            .entry(name.to_string())
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    pub fn check(
+        &self,
+        day: u32,
+        name: &str,
+        part: Part,
+        answer: &str,
+    ) -> Status {
+        return match self.get(day, name, part) {
+            None => Status::Unknown,
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+            },
+        };
+    }
+
+    /// Every known answer, in day, input name and part order.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (u32, &str, Part, &str)> {
+        return self.answers.iter().flat_map(|(day, inputs)| {
+            inputs.iter().flat_map(move |(name, parts)| {
+                parts.iter().map(move |(part, answer)| {
+                    (*day, name.as_str(), *part, answer.as_str())
+                })
+            })
+        });
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::error::Result;
+use crate::input::Input;
+use crate::registry::Runner;
+use crate::solution::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        return Stats {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        };
+    }
+}
+
+/// Timings of a day over many runs, parse and each part separately.
+#[derive(Debug)]
+pub struct Bench {
+    pub day: u32,
+    pub input: String,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+/// Run a day `iterations` times, re-parsing the input every time.
+pub fn bench(
+    day: u32,
+    runner: Runner,
+    input: &Input,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Bench> {
+    assert!(iterations > 0);
+
+    // stdin can only be read once
+    let input = match input {
+        Input::Stdin => Input::Text(input.read()?),
+        _ => input.clone(),
+    };
+
+    let mut parse_samples = Vec::new();
+    let mut part_samples = vec![Vec::new(); parts.len()];
+    for _ in 0..iterations {
+        let report = runner(&input, parts)?;
+        parse_samples.push(report.parse_time);
+        for (i, (_, _, elapsed)) in
+            report.answers.iter().enumerate()
+        {
+            part_samples[i].push(*elapsed);
+        }
+    }
+
+    return Ok(Bench {
+        day,
+        input: input.to_string(),
+        iterations,
+        parse: Stats::from_samples(&parse_samples),
+        parts: parts
+            .iter()
+            .zip(part_samples)
+            .map(|(part, samples)| {
+                (*part, Stats::from_samples(&samples))
+            })
+            .collect(),
+    });
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => {
+                write!(out, "\\u{:04x}", c as u32).unwrap()
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    return out;
+}
+
+fn stats_json(stats: &Stats) -> String {
+    return format!(
+        "{{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.max.as_nanos()
+    );
+}
+
+/// Render results as a JSON array, one object per day.
+pub fn to_json(benches: &[Bench]) -> String {
+    let mut s = String::from("[");
+    for (i, b) in benches.iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        write!(
+            s,
+            "\n  {{\"day\": {}, \"input\": {}, \"iterations\": {}, \"parse\": {}",
+            b.day,
+            json_string(&b.input),
+            b.iterations,
+            stats_json(&b.parse)
+        )
+        .unwrap();
+        for (part, stats) in b.parts.iter() {
+            write!(
+                s,
+                ", \"part{}\": {}",
+                part,
+                stats_json(stats)
+            )
+            .unwrap();
+        }
+        s.push('}');
+    }
+    s.push_str("\n]");
+    return s;
+}
@@ -0,0 +1,94 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// The input could not be read at all.
+    Io(io::Error),
+    /// A line (1-based) that doesn't look like the puzzle says.
+    BadLine {
+        line: usize,
+        content: String,
+        reason: String,
+    },
+    /// A line is missing a regex capture or field we need.
+    Missing {
+        line: usize,
+        content: String,
+        field: String,
+    },
+    /// Every line parsed, but they don't fit together.
+    Inconsistent(String),
+    /// An answer too big for the type it's worked out in.
+    Overflow(String),
+    /// A supporting file, like `answers.toml`, is malformed.
+    BadFile { path: PathBuf, reason: String },
+    /// Downloading from adventofcode.com went wrong.
+    Fetch(String),
+    /// Submitting an answer went wrong, or was refused.
+    Submit(String),
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io(e) => {
+                write!(f, "could not read input: {}", e)
+            }
+            AocError::BadLine {
+                line,
+                content,
+                reason,
+            } => write!(
+                f,
+                "line {}: {}: {:?}",
+                line, reason, content
+            ),
+            AocError::Missing {
+                line,
+                content,
+                field,
+            } => {
+                write!(
+                    f,
+                    "line {}: missing {}: {:?}",
+                    line, field, content
+                )
+            }
+            AocError::Inconsistent(reason) => {
+                write!(f, "inconsistent input: {}", reason)
+            }
+            AocError::Overflow(reason) => {
+                write!(f, "overflow: {}", reason)
+            }
+            AocError::BadFile { path, reason } => {
+                write!(f, "{}: {}", path.display(), reason)
+            }
+            AocError::Fetch(reason) => {
+                write!(f, "could not fetch: {}", reason)
+            }
+            AocError::Submit(reason) => {
+                write!(f, "could not submit: {}", reason)
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        };
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        return AocError::Io(e);
+    }
+}
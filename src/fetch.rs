@@ -0,0 +1,200 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::error::{AocError, Result};
+
+pub const YEAR: u32 = 2023;
+
+const USER_AGENT: &str = "github.com/patrick-ryan/aoc23";
+
+/// Somewhere puzzle inputs can be downloaded from.
+pub trait Fetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String>;
+}
+
+/// Fetches inputs from the Advent of Code site, logged in with the
+/// `session` cookie.
+pub struct HttpFetcher {
+    base_url: String,
+    // only needed once something has to be downloaded, so a cache
+    // full of inputs works without one
+    session: Option<String>,
+    retries: u32,
+    backoff: Duration,
+    agent: ureq::Agent,
+}
+
+pub(crate) const BASE_URL: &str = "https://adventofcode.com";
+
+pub(crate) fn agent() -> ureq::Agent {
+    return ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(30))
+        .user_agent(USER_AGENT)
+        .build();
+}
+
+/// The session token in `AOC_SESSION`.
+pub fn session_from_env() -> Option<String> {
+    return env::var("AOC_SESSION")
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty());
+}
+
+pub(crate) const NO_SESSION: &str =
+    "set AOC_SESSION to your adventofcode.com session cookie";
+
+impl HttpFetcher {
+    pub fn new(session: &str) -> Self {
+        return HttpFetcher {
+            base_url: BASE_URL.to_string(),
+            session: Some(session.to_string()),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+            agent: agent(),
+        };
+    }
+
+    /// Use the session token in `AOC_SESSION`. Without one every
+    /// fetch fails, but nothing does until a fetch is tried.
+    pub fn from_env() -> Self {
+        let mut fetcher = HttpFetcher::new("");
+        fetcher.session = session_from_env();
+        return fetcher;
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url =
+            base_url.trim_end_matches('/').to_string();
+        return self;
+    }
+
+    /// Retry server errors and dropped connections `retries` times,
+    /// waiting `backoff` and then twice as long each time.
+    pub fn with_retries(
+        mut self,
+        retries: u32,
+        backoff: Duration,
+    ) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        return self;
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String> {
+        let Some(session) = &self.session else {
+            return Err(AocError::Fetch(NO_SESSION.to_string()));
+        };
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url, year, day
+        );
+
+        let mut backoff = self.backoff;
+        let mut attempt = 0;
+        loop {
+            let response = self
+                .agent
+                .get(&url)
+                .set("Cookie", &format!("session={}", session))
+                .call();
+
+            let reason = match response {
+                Ok(response) => {
+                    return response.into_string().map_err(|e| {
+                        AocError::Fetch(format!("{}: {}", url, e))
+                    });
+                }
+                Err(ureq::Error::Status(code, _))
+                    if code < 500 =>
+                {
+                    // a bad session or a day that isn't out yet, trying
+                    // again won't help
+                    return Err(AocError::Fetch(format!(
+                        "{}: HTTP {}",
+                        url, code
+                    )));
+                }
+                Err(ureq::Error::Status(code, _)) => {
+                    format!("HTTP {}", code)
+                }
+                Err(e) => e.to_string(),
+            };
+
+            if attempt >= self.retries {
+                return Err(AocError::Fetch(format!(
+                    "{}: {} (after {} attempts)",
+                    url,
+                    reason,
+                    attempt + 1
+                )));
+            }
+            attempt += 1;
+            thread::sleep(backoff);
+            backoff *= 2;
+        }
+    }
+}
+
+/// Puzzle inputs kept on disk as `<dir>/<year>/<day>.txt`, fetched
+/// the first time they're asked for.
+pub struct InputCache<F: Fetcher> {
+    dir: PathBuf,
+    fetcher: F,
+}
+
+/// `$AOC_CACHE_DIR`, or `aoc` in the user's cache directory.
+pub fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return Path::new(&dir).join("aoc");
+    }
+    if let Some(home) = env::var_os("HOME") {
+        return Path::new(&home).join(".cache").join("aoc");
+    }
+    return env::temp_dir().join("aoc");
+}
+
+impl<F: Fetcher> InputCache<F> {
+    pub fn new<P: AsRef<Path>>(dir: P, fetcher: F) -> Self {
+        return InputCache {
+            dir: dir.as_ref().to_path_buf(),
+            fetcher,
+        };
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        return self
+            .dir
+            .join(year.to_string())
+            .join(format!("{:02}.txt", day));
+    }
+
+    pub fn contains(&self, year: u32, day: u32) -> bool {
+        return self.path(year, day).is_file();
+    }
+
+    /// The cached input file, fetching it first on a miss.
+    pub fn get(&self, year: u32, day: u32) -> Result<PathBuf> {
+        let path = self.path(year, day);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let text = self.fetcher.fetch(year, day)?;
+        fs::create_dir_all(path.parent().unwrap())?;
+        // write to the side and rename, so an interrupted write can't
+        // leave a truncated input that looks cached
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, text)?;
+        fs::rename(&partial, &path)?;
+        return Ok(path);
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on an integer plane. `y` grows downwards, so on a
+/// grid `x` is the column and `y` the row.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The difference between two points.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct Vector {
+    pub dx: i64,
+    pub dy: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        return Point { x, y };
+    }
+
+    /// Steps between the points moving only along the axes.
+    pub fn manhattan(self, other: Point) -> i64 {
+        return (self - other).manhattan();
+    }
+
+    /// Steps between the points moving like a chess king.
+    pub fn chebyshev(self, other: Point) -> i64 {
+        return (self - other).chebyshev();
+    }
+
+    /// The neighbouring point in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        return self + direction.vector();
+    }
+}
+
+impl Vector {
+    pub const fn new(dx: i64, dy: i64) -> Self {
+        return Vector { dx, dy };
+    }
+
+    pub fn manhattan(self) -> i64 {
+        return self.dx.abs() + self.dy.abs();
+    }
+
+    pub fn chebyshev(self) -> i64 {
+        return self.dx.abs().max(self.dy.abs());
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "({}, {})", self.x, self.y);
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        return Point::new(self.x + v.dx, self.y + v.dy);
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        return self + -v;
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        return Vector::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        return Vector::new(
+            self.dx + other.dx,
+            self.dy + other.dy,
+        );
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        return self + -other;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        return Vector::new(-self.dx, -self.dy);
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, n: i64) -> Vector {
+        return Vector::new(self.dx * n, self.dy * n);
+    }
+}
+
+/// A compass direction, with north pointing up the grid.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// The four along the axes, clockwise from north.
+    pub const CARDINAL: [Direction; 4] =
+        [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// All eight, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    /// One step in this direction.
+    pub fn vector(self) -> Vector {
+        let (dx, dy) = match self {
+            Direction::N => (0, -1),
+            Direction::NE => (1, -1),
+            Direction::E => (1, 0),
+            Direction::SE => (1, 1),
+            Direction::S => (0, 1),
+            Direction::SW => (-1, 1),
+            Direction::W => (-1, 0),
+            Direction::NW => (-1, -1),
+        };
+        return Vector::new(dx, dy);
+    }
+
+    /// Turn clockwise by `eighths` of a full turn.
+    fn rotate(self, eighths: usize) -> Direction {
+        // ALL is in declaration order
+        return Direction::ALL[(self as usize + eighths) % 8];
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        return self.rotate(6);
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        return self.rotate(2);
+    }
+
+    pub fn reverse(self) -> Direction {
+        return self.rotate(4);
+    }
+}
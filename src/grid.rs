@@ -0,0 +1,566 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::error::Result;
+use crate::geometry::{Direction, Point};
+use crate::input::Input;
+
+/// A rectangular grid of cells, stored row by row, indexed by
+/// [`Point`]s with `x` the column and `y` the row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+fn at(row: usize, col: usize) -> Point {
+    return Point::new(col as i64, row as i64);
+}
+
+/// Every point of a `width` by `height` grid, row by row.
+fn points(
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = Point> {
+    return (0..height).flat_map(move |row| {
+        (0..width).map(move |col| at(row, col))
+    });
+}
+
+impl<T> Grid<T> {
+    /// A grid from its rows, `None` if they aren't all as wide.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |x| x.len());
+        if rows.iter().any(|x| x.len() != width) {
+            return None;
+        }
+        return Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        });
+    }
+
+    /// Parse one cell per character, one row per non-blank line.
+    /// `cell` returns `None` for characters that don't belong.
+    pub fn parse_with(
+        input: &Input,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for line in input.lines()? {
+            let line = line?;
+            if line.text.is_empty() {
+                continue;
+            }
+            let row = line
+                .text
+                .chars()
+                .map(|c| {
+                    cell(c).ok_or_else(|| {
+                        line.error(format!("unexpected {:?}", c))
+                    })
+                })
+                .collect::<Result<Vec<T>>>()?;
+            if rows.first().is_some_and(|x| x.len() != row.len())
+            {
+                return Err(
+                    line.error("row has a different width")
+                );
+            }
+            rows.push(row);
+        }
+        return Ok(Grid::from_rows(rows).unwrap());
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    /// Where `point` is in `cells`, if it's inside the grid at all.
+    /// Every lookup goes through here.
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let col = usize::try_from(point.x).ok()?;
+        let row = usize::try_from(point.y).ok()?;
+        if col >= self.width || row >= self.height {
+            return None;
+        }
+        return Some(row * self.width + col);
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        return self.index_of(point).is_some();
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        return self.index_of(point).map(|i| &self.cells[i]);
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        return self.index_of(point).map(|i| &mut self.cells[i]);
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        return points(self.width, self.height);
+    }
+
+    /// Every cell and its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    /// Positions of every cell matching `pred`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        pred: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        return self
+            .iter()
+            .filter(move |(_, cell)| pred(cell))
+            .map(|(point, _)| point);
+    }
+
+    fn neighbours(
+        &self,
+        point: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + '_ {
+        return directions
+            .iter()
+            .map(move |d| point.step(*d))
+            .filter(|x| self.contains(*x));
+    }
+
+    /// The up to 4 orthogonal neighbours of `point` inside the
+    /// grid, clockwise from above.
+    pub fn neighbours4(
+        &self,
+        point: Point,
+    ) -> impl Iterator<Item = Point> + '_ {
+        return self.neighbours(point, &Direction::CARDINAL);
+    }
+
+    /// The up to 8 neighbours of `point` inside the grid,
+    /// diagonals included, clockwise from above.
+    pub fn neighbours8(
+        &self,
+        point: Point,
+    ) -> impl Iterator<Item = Point> + '_ {
+        return self.neighbours(point, &Direction::ALL);
+    }
+
+    /// Cells from `start` (not included) going in `direction`,
+    /// until the edge of the grid.
+    pub fn ray(
+        &self,
+        start: Point,
+        direction: Direction,
+    ) -> impl Iterator<Item = &T> {
+        return (1..)
+            .map(move |i| start + direction.vector() * i)
+            .map_while(|point| self.get(point));
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        return &self.cells
+            [row * self.width..(row + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return (0..self.height).map(|row| self.row(row));
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width);
+        return (0..self.height)
+            .map(move |row| &self[at(row, col)]);
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(|col| self.column(col));
+    }
+
+    /// Cells from `start` going down and to the right.
+    pub fn diagonal(
+        &self,
+        start: Point,
+    ) -> impl Iterator<Item = &T> {
+        return self
+            .get(start)
+            .into_iter()
+            .chain(self.ray(start, Direction::SE));
+    }
+
+    /// Cells from `start` going down and to the left.
+    pub fn anti_diagonal(
+        &self,
+        start: Point,
+    ) -> impl Iterator<Item = &T> {
+        return self
+            .get(start)
+            .into_iter()
+            .chain(self.ray(start, Direction::SW));
+    }
+
+    /// Every down-right diagonal, from the bottom left corner to
+    /// the top right one.
+    pub fn diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|row| at(row, 0))
+            .chain((1..self.width).map(|col| at(0, col)));
+        return starts.map(|start| self.diagonal(start));
+    }
+
+    /// Every down-left diagonal, from the top left corner to the
+    /// bottom right one.
+    pub fn anti_diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last = self.width.saturating_sub(1);
+        let starts = (0..self.width).map(|col| at(0, col)).chain(
+            (1..self.height).map(move |row| at(row, last)),
+        );
+        return starts.map(|start| self.anti_diagonal(start));
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        };
+    }
+
+    /// Every horizontal run of cells matching `pred`, row by row.
+    /// Runs stop at the end of a row.
+    pub fn spans(&self, pred: impl Fn(&T) -> bool) -> Vec<Span> {
+        let mut spans = Vec::new();
+        for (row, cells) in self.rows().enumerate() {
+            let mut start = None;
+            for (col, cell) in cells.iter().enumerate() {
+                match (pred(cell), start) {
+                    (true, None) => start = Some(col),
+                    (false, Some(first)) => {
+                        spans.push(Span::new(
+                            at(row, first),
+                            col - first,
+                        ));
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(first) = start {
+                spans.push(Span::new(
+                    at(row, first),
+                    self.width - first,
+                ));
+            }
+        }
+        return spans;
+    }
+
+    /// The cells of a span, left to right.
+    pub fn span_cells<'a>(
+        &'a self,
+        span: &'a Span,
+    ) -> impl Iterator<Item = &'a T> {
+        return span.points().map(|point| &self[point]);
+    }
+
+    /// Every cell matching `pred` that can be reached from `start`
+    /// through orthogonal neighbours that also match. Empty if
+    /// `start` doesn't match.
+    pub fn flood_fill(
+        &self,
+        start: Point,
+        pred: impl Fn(&T) -> bool,
+    ) -> Region {
+        let mut points = BTreeSet::new();
+        if !self.get(start).is_some_and(&pred) {
+            return Region { points };
+        }
+
+        let mut queue = VecDeque::from([start]);
+        points.insert(start);
+        while let Some(point) = queue.pop_front() {
+            for next in self.neighbours4(point) {
+                if pred(&self[next]) && points.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        return Region { points };
+    }
+
+    /// Every orthogonally connected region of cells matching
+    /// `pred`, in order of their first cell.
+    pub fn regions(
+        &self,
+        pred: impl Fn(&T) -> bool,
+    ) -> Vec<Region> {
+        let mut seen = BTreeSet::new();
+        let mut regions = Vec::new();
+        for (point, cell) in self.iter() {
+            if seen.contains(&point) || !pred(cell) {
+                continue;
+            }
+            let region = self.flood_fill(point, &pred);
+            seen.extend(region.points.iter().copied());
+            regions.push(region);
+        }
+        return regions;
+    }
+
+    /// A grid of `width` by `height` built from each `(row, col)`.
+    fn build(
+        width: usize,
+        height: usize,
+        cell: impl Fn((usize, usize)) -> T,
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(cell)
+            .collect();
+        return Grid {
+            width,
+            height,
+            cells,
+        };
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        return Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        };
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Self {
+        return Grid::build(
+            self.height,
+            self.width,
+            |(row, col)| self[at(col, row)].clone(),
+        );
+    }
+
+    /// Turn a quarter clockwise.
+    pub fn rotate_right(&self) -> Self {
+        let last = self.height.saturating_sub(1);
+        return Grid::build(
+            self.height,
+            self.width,
+            |(row, col)| self[at(last - col, row)].clone(),
+        );
+    }
+
+    /// Turn a quarter anticlockwise.
+    pub fn rotate_left(&self) -> Self {
+        let last = self.width.saturating_sub(1);
+        return Grid::build(
+            self.height,
+            self.width,
+            |(row, col)| self[at(col, last - row)].clone(),
+        );
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let last = self.width.saturating_sub(1);
+        return Grid::build(
+            self.width,
+            self.height,
+            |(row, col)| self[at(row, last - col)].clone(),
+        );
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let last = self.height.saturating_sub(1);
+        return Grid::build(
+            self.width,
+            self.height,
+            |(row, col)| self[at(last - row, col)].clone(),
+        );
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid of characters, one row per non-blank line.
+    pub fn parse(input: &Input) -> Result<Self> {
+        return Grid::parse_with(input, Some);
+    }
+
+    /// The characters of a span, like the digits of a number.
+    pub fn span_text(&self, span: &Span) -> String {
+        return self.span_cells(span).collect();
+    }
+}
+
+/// Cells around `points` inside `grid`, diagonals included, in
+/// reading order.
+fn border<T>(
+    grid: &Grid<T>,
+    points: impl Iterator<Item = Point>,
+    inside: impl Fn(Point) -> bool,
+) -> Vec<Point> {
+    let around: BTreeSet<Point> = points
+        .flat_map(|point| grid.neighbours8(point))
+        .filter(|point| !inside(*point))
+        .collect();
+    let mut around: Vec<Point> = around.into_iter().collect();
+    around.sort_by_key(|point| (point.y, point.x));
+    return around;
+}
+
+/// A horizontal run of `len` cells starting at `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: Point,
+    pub len: usize,
+}
+
+impl Span {
+    pub fn new(start: Point, len: usize) -> Self {
+        return Span { start, len };
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let start = self.start;
+        return (0..self.len as i64)
+            .map(move |i| Point::new(start.x + i, start.y));
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        return point.y == self.start.y
+            && point.x >= self.start.x
+            && point.x < self.start.x + self.len as i64;
+    }
+
+    /// The cells around the span inside `grid`, diagonals included.
+    pub fn neighbours<T>(&self, grid: &Grid<T>) -> Vec<Point> {
+        return border(grid, self.points(), |x| self.contains(x));
+    }
+
+    /// The cells around the span whose contents match `pred`.
+    pub fn touching<T>(
+        &self,
+        grid: &Grid<T>,
+        pred: impl Fn(&T) -> bool,
+    ) -> Vec<Point> {
+        let mut around = self.neighbours(grid);
+        around.retain(|point| pred(&grid[*point]));
+        return around;
+    }
+}
+
+/// A connected set of cells, see [`Grid::flood_fill`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Region {
+    pub points: BTreeSet<Point>,
+}
+
+impl Region {
+    pub fn len(&self) -> usize {
+        return self.points.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.points.is_empty();
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        return self.points.contains(&point);
+    }
+
+    /// The cells around the region inside `grid`, diagonals
+    /// included.
+    pub fn neighbours<T>(&self, grid: &Grid<T>) -> Vec<Point> {
+        return border(grid, self.points.iter().copied(), |x| {
+            self.contains(x)
+        });
+    }
+
+    /// The cells around the region whose contents match `pred`.
+    pub fn touching<T>(
+        &self,
+        grid: &Grid<T>,
+        pred: impl Fn(&T) -> bool,
+    ) -> Vec<Point> {
+        let mut around = self.neighbours(grid);
+        around.retain(|point| pred(&grid[*point]));
+        return around;
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        return self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{} is outside a {}x{} grid",
+                point, self.width, self.height
+            )
+        });
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        return self.get_mut(point).unwrap_or_else(|| {
+            panic!(
+                "{} is outside a {}x{} grid",
+                point, width, height
+            )
+        });
+    }
+}
+
+/// One row per line. Cells wider than one character are padded to
+/// line up, with a space between columns.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells: Vec<String> =
+            self.cells.iter().map(|x| x.to_string()).collect();
+        let widest = cells
+            .iter()
+            .map(|x| x.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (row, line) in
+            cells.chunks(self.width.max(1)).enumerate()
+        {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            if widest <= 1 {
+                write!(f, "{}", line.concat())?;
+            } else {
+                let padded: Vec<String> = line
+                    .iter()
+                    .map(|x| format!("{:>w$}", x, w = widest))
+                    .collect();
+                write!(f, "{}", padded.join(" "))?;
+            }
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+
+use crate::error::{AocError, Result};
+use crate::read_lines;
+
+pub type Lines = Box<dyn Iterator<Item = io::Result<String>>>;
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A file anywhere on disk.
+    File(PathBuf),
+    /// Everything piped to stdin.
+    Stdin,
+    /// An in-memory string, mostly for tests.
+    Text(String),
+    /// A named input kept next to the day's code, see [`day_input`].
+    Day(u32, String),
+}
+
+impl Input {
+    pub fn file<P: AsRef<Path>>(path: P) -> Self {
+        return Input::File(path.as_ref().to_path_buf());
+    }
+
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        return Input::Text(text.into());
+    }
+
+    pub fn day(day: u32, name: &str) -> Self {
+        return Input::Day(day, name.to_string());
+    }
+
+    /// The file backing this input, if there is one.
+    pub fn path(&self) -> Option<PathBuf> {
+        return match self {
+            Input::File(path) => Some(path.clone()),
+            Input::Day(day, name) => Some(day_input(*day, name)),
+            Input::Stdin | Input::Text(_) => None,
+        };
+    }
+
+    /// Read the whole input into a string.
+    pub fn read(&self) -> io::Result<String> {
+        return match self {
+            Input::Stdin => {
+                let mut s = String::new();
+                io::stdin().read_to_string(&mut s)?;
+                Ok(s)
+            }
+            Input::Text(text) => Ok(text.clone()),
+            Input::File(_) | Input::Day(_, _) => {
+                crate::read_input(self.path().unwrap())
+            }
+        };
+    }
+
+    /// Iterate over the numbered lines of the input.
+    pub fn lines(
+        &self,
+    ) -> io::Result<Box<dyn Iterator<Item = Result<Line>>>> {
+        let lines =
+            self.read_lines()?.enumerate().map(|(i, text)| {
+                return Ok(Line {
+                    number: i + 1,
+                    text: text?,
+                });
+            });
+        return Ok(Box::new(lines));
+    }
+
+    /// Iterate over the lines of the input.
+    pub fn read_lines(&self) -> io::Result<Lines> {
+        return match self {
+            Input::Stdin => {
+                Ok(Box::new(io::stdin().lock().lines()))
+            }
+            Input::Text(text) => {
+                let lines = text
+                    .lines()
+                    .map(|x| Ok(x.to_string()))
+                    .collect::<Vec<_>>();
+                Ok(Box::new(lines.into_iter()))
+            }
+            Input::File(_) | Input::Day(_, _) => {
+                Ok(Box::new(read_lines(self.path().unwrap())?))
+            }
+        };
+    }
+}
+
+/// One line of input and its 1-based line number, so parsers can
+/// say exactly where things went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub number: usize,
+    pub text: String,
+}
+
+impl Line {
+    pub fn error<S: Into<String>>(&self, reason: S) -> AocError {
+        return AocError::BadLine {
+            line: self.number,
+            content: self.text.clone(),
+            reason: reason.into(),
+        };
+    }
+
+    pub fn missing(&self, field: &str) -> AocError {
+        return AocError::Missing {
+            line: self.number,
+            content: self.text.clone(),
+            field: field.to_string(),
+        };
+    }
+
+    pub fn parse_int<T: FromStr>(&self, s: &str) -> Result<T> {
+        return s.trim().parse::<T>().map_err(|_| {
+            self.error(format!("bad integer {:?}", s))
+        });
+    }
+
+    /// Parse every whitespace-separated integer in `s`.
+    pub fn parse_ints<T: FromStr>(
+        &self,
+        s: &str,
+    ) -> Result<Vec<T>> {
+        return s
+            .split_whitespace()
+            .map(|x| self.parse_int(x))
+            .collect();
+    }
+
+    pub fn captures<'a>(
+        &'a self,
+        re: &Regex,
+    ) -> Result<Captures<'a>> {
+        return re.captures(&self.text).ok_or_else(|| {
+            self.error(format!("does not match {}", re.as_str()))
+        });
+    }
+
+    pub fn capture<'a>(
+        &self,
+        caps: &Captures<'a>,
+        name: &str,
+    ) -> Result<&'a str> {
+        return caps
+            .name(name)
+            .map(|m| m.as_str())
+            .ok_or_else(|| self.missing(name));
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Text(_) => write!(f, "<text>"),
+            Input::File(_) | Input::Day(_, _) => {
+                write!(f, "{}", self.path().unwrap().display())
+            }
+        }
+    }
+}
+
+/// The `src/NNrs` directory holding a day's code and inputs.
+pub fn day_dir(day: u32) -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("{:02}rs", day));
+}
+
+/// The file for a named input, e.g. `in` is `in.txt` and `ex2` is
+/// `ex2.in.txt`.
+pub fn day_input(day: u32, name: &str) -> PathBuf {
+    let file_name = if name == "in" {
+        "in.txt".to_string()
+    } else {
+        format!("{}.in.txt", name)
+    };
+    return day_dir(day).join(file_name);
+}
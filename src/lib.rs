@@ -0,0 +1,103 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead};
+use std::path::Path;
+
+#[path = "01rs/mod.rs"]
+pub mod day01;
+#[path = "02rs/mod.rs"]
+pub mod day02;
+#[path = "03rs/mod.rs"]
+pub mod day03;
+#[path = "04rs/mod.rs"]
+pub mod day04;
+#[path = "05rs/mod.rs"]
+pub mod day05;
+#[path = "06rs/mod.rs"]
+pub mod day06;
+#[path = "07rs/mod.rs"]
+pub mod day07;
+#[path = "08rs/mod.rs"]
+pub mod day08;
+#[path = "09rs/mod.rs"]
+pub mod day09;
+
+pub mod aho_corasick;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod ranges;
+pub mod registry;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+
+pub use error::{AocError, Result};
+pub use input::Input;
+pub use solution::Solution;
+
+/// Read a whole input file into a string.
+pub fn read_input<P>(filename: P) -> io::Result<String>
+where
+    P: AsRef<Path>,
+{
+    fs::read_to_string(filename)
+}
+
+/// Iterate over the lines of an input file.
+pub fn read_lines<P>(
+    filename: P,
+) -> io::Result<io::Lines<io::BufReader<File>>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(filename)?;
+    Ok(io::BufReader::new(file).lines())
+}
+
+/// Group lines into paragraphs, split on blank lines.
+pub fn paragraphs<I, S>(lines: I) -> Paragraphs<I::IntoIter>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    Paragraphs {
+        lines: lines.into_iter(),
+    }
+}
+
+pub struct Paragraphs<I> {
+    lines: I,
+}
+
+impl<I, S> Iterator for Paragraphs<I>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut paragraph = Vec::new();
+        for line in self.lines.by_ref() {
+            let line = line.as_ref();
+            if line.is_empty() {
+                if paragraph.is_empty() {
+                    // skip runs of blank lines
+                    continue;
+                }
+                break;
+            }
+            paragraph.push(line.to_string());
+        }
+        if paragraph.is_empty() {
+            return None;
+        }
+        return Some(paragraph);
+    }
+}
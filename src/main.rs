@@ -1,3 +1,586 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use aoc23::answers::{self, Answers, Status};
+use aoc23::bench::{self, Bench};
+use aoc23::day02::{self, BagReport, CubeSet, Day02};
+use aoc23::fetch::{self, HttpFetcher, InputCache};
+use aoc23::registry::{find_day, run_all, Runner, DAYS};
+use aoc23::scaffold;
+use aoc23::solution::Part;
+use aoc23::submit::{
+    self, HttpSubmitter, SubmissionLog, Verdict,
+};
+use aoc23::{Input, Solution};
+
+const USAGE: &str = "\
+usage: aoc (--day N | --all) [--part P] [--input NAME] [PATH | -]
+           [--verify] [--record]
+       aoc bench (--day N | --all) [--part P] [--input NAME]
+           [--iterations N] [--json]
+       aoc fetch (--day N | --all)
+       aoc submit --day N --part P
+       aoc bag [--bag CUBES | --bag-file PATH] [--input NAME]
+       aoc new-day N
+
+  -d, --day N       run a single day
+  -a, --all         run every day
+  -p, --part P      only run part 1 or 2 (default: both)
+  -i, --input NAME  input to run: a name under src/NNrs (`in` for
+                    in.txt, `ex` for ex.in.txt, ...), a path with
+                    a `/`, or `-` for stdin (default: in)
+  PATH | -          same as --input with a path or stdin
+      --verify      check answers against answers.toml
+      --record      save answers to answers.toml as known-good
+
+bench times parse, part 1 and part 2 separately:
+  -n, --iterations N  how many times to run each day (default: 10)
+      --json          print results as JSON
+
+fetch downloads puzzle inputs into the cache ($AOC_CACHE_DIR, or
+~/.cache/aoc), using the session cookie in $AOC_SESSION. `in` is
+read from the cache whenever src/NNrs/in.txt is missing or empty.
+
+submit runs a part on the real input and sends the answer to the
+site. Every verdict is logged next to the cached inputs, and answers
+already known to be wrong, past a known too high/too low bound, or
+sent while the site wants us to wait are never resubmitted.
+
+bag checks every day 02 game against a bag of cubes and reports
+the smallest bag each game needs, the first reveal the bag couldn't
+have made, and how the minimal powers are spread:
+      --bag CUBES      the bag, written like a reveal (default:
+                       \"12 red, 13 green, 14 blue\")
+      --bag-file PATH  a TOML file of counts by color, e.g.
+                       `red = 12`; colors left out aren't in the bag
+
+new-day scaffolds src/NNrs from src/template.rs and registers it
+(also available as `cargo new-day N`)";
+
+#[derive(PartialEq, Eq)]
+enum Command {
+    Run,
+    Bench,
+    Fetch,
+    Submit,
+    Bag,
+}
+
+enum InputArg {
+    Named(String),
+    File(PathBuf),
+    Stdin,
+}
+
+struct Args {
+    command: Command,
+    days: Vec<u32>,
+    parts: Vec<Part>,
+    input: InputArg,
+    verify: bool,
+    record: bool,
+    iterations: usize,
+    json: bool,
+    bag: Option<String>,
+    bag_file: Option<PathBuf>,
+}
+
+fn parse_input_arg(value: &str) -> InputArg {
+    if value == "-" {
+        return InputArg::Stdin;
+    }
+    // only something with a separator is a path, so a stray file
+    // called `ex` can't shadow the example
+    let path = Path::new(value);
+    if path.components().count() > 1 {
+        return InputArg::File(path.to_path_buf());
+    }
+    return InputArg::Named(value.to_string());
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut command = Command::Run;
+    let mut day = None;
+    let mut all = false;
+    let mut parts = Part::ALL.to_vec();
+    let mut input = InputArg::Named("in".to_string());
+    let mut verify = false;
+    let mut record = false;
+    let mut iterations = None;
+    let mut json = false;
+    let mut bag = None;
+    let mut bag_file = None;
+
+    let mut args = args;
+    if args.first().is_some_and(|x| x == "bench") {
+        command = Command::Bench;
+        args = &args[1..];
+    } else if args.first().is_some_and(|x| x == "fetch") {
+        command = Command::Fetch;
+        args = &args[1..];
+    } else if args.first().is_some_and(|x| x == "submit") {
+        command = Command::Submit;
+        args = &args[1..];
+    } else if args.first().is_some_and(|x| x == "bag") {
+        command = Command::Bag;
+        args = &args[1..];
+        // it's only about day 02
+        day = Some(2);
+    }
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        let mut value = |name: &str| {
+            args_iter
+                .next()
+                .ok_or(format!("{} needs a value", name))
+        };
+        match arg.as_str() {
+            "-d" | "--day" => {
+                let v = value(arg)?;
+                day = Some(v.parse::<u32>().map_err(|_| {
+                    format!("day must be a number, got {:?}", v)
+                })?);
+            }
+            "-a" | "--all" => all = true,
+            "-p" | "--part" => parts = vec![value(arg)?.parse()?],
+            "-i" | "--input" => {
+                input = parse_input_arg(value(arg)?)
+            }
+            "--verify" => verify = true,
+            "--record" => record = true,
+            "-n" | "--iterations" => {
+                let v = value(arg)?;
+                let n = v.parse::<usize>().unwrap_or(0);
+                if n == 0 {
+                    return Err(format!(
+                        "iterations must be a positive number, got {:?}",
+                        v
+                    ));
+                }
+                iterations = Some(n);
+            }
+            "--json" => json = true,
+            "--bag" => bag = Some(value(arg)?.to_string()),
+            "--bag-file" => {
+                bag_file = Some(PathBuf::from(value(arg)?))
+            }
+            "-" => input = InputArg::Stdin,
+            _ if arg.starts_with('-') => {
+                return Err(format!("unknown option {:?}", arg));
+            }
+            _ => input = InputArg::File(PathBuf::from(arg)),
+        }
+    }
+
+    let days = match (day, all) {
+        (Some(_), true) => {
+            return Err("use either --day or --all".to_string());
+        }
+        (Some(d), false) => vec![d],
+        (None, true) => DAYS.iter().map(|(d, _)| *d).collect(),
+        (None, false) => {
+            return Err("no day given".to_string());
+        }
+    };
+    if days.len() > 1 && !matches!(input, InputArg::Named(_)) {
+        return Err(
+            "--all only works with a named input".to_string()
+        );
+    }
+    if (verify || record) && !matches!(input, InputArg::Named(_))
+    {
+        return Err(
+            "answers are only kept for named inputs".to_string()
+        );
+    }
+    if command == Command::Bench && (verify || record) {
+        return Err("--verify and --record don't apply to bench"
+            .to_string());
+    }
+    if command == Command::Fetch
+        && (verify || record || iterations.is_some() || json)
+    {
+        return Err("fetch only takes --day or --all".to_string());
+    }
+    if command == Command::Submit {
+        if days.len() != 1 || parts.len() != 1 {
+            return Err("submit needs one --day and one --part"
+                .to_string());
+        }
+        if !matches!(&input, InputArg::Named(name) if name == "in")
+        {
+            return Err(
+                "submit only sends answers for the real input"
+                    .to_string(),
+            );
+        }
+        if verify || record || iterations.is_some() || json {
+            return Err(
+                "submit only takes --day and --part".to_string()
+            );
+        }
+    }
+    if command == Command::Bag {
+        if days != [2] {
+            return Err("bag only works on day 02".to_string());
+        }
+        if bag.is_some() && bag_file.is_some() {
+            return Err(
+                "use either --bag or --bag-file".to_string()
+            );
+        }
+        if verify || record || iterations.is_some() || json {
+            return Err(
+                "bag only takes --bag, --bag-file and --input"
+                    .to_string(),
+            );
+        }
+    } else if bag.is_some() || bag_file.is_some() {
+        return Err(
+            "--bag and --bag-file only apply to bag".to_string()
+        );
+    }
+    if command == Command::Run && (iterations.is_some() || json) {
+        return Err(
+            "--iterations and --json only apply to bench"
+                .to_string(),
+        );
+    }
+
+    return Ok(Args {
+        command,
+        days,
+        parts,
+        input,
+        verify,
+        record,
+        iterations: iterations.unwrap_or(10),
+        json,
+        bag,
+        bag_file,
+    });
+}
+
+fn input_cache() -> InputCache<HttpFetcher> {
+    return InputCache::new(
+        fetch::default_cache_dir(),
+        HttpFetcher::from_env(),
+    );
+}
+
+fn cached_input(day: u32) -> PathBuf {
+    return input_cache().get(fetch::YEAR, day).unwrap_or_else(
+        |e| {
+            eprintln!("error: day {:02}: {}", day, e);
+            process::exit(1);
+        },
+    );
+}
+
+fn get_day(args: &Args, day: u32) -> (Runner, Input) {
+    let runner = match find_day(day) {
+        Some(runner) => runner,
+        None => {
+            eprintln!("error: day {} is not implemented", day);
+            process::exit(2);
+        }
+    };
+
+    let input = match &args.input {
+        InputArg::Named(name) => {
+            let input = Input::day(day, name);
+            // puzzle inputs aren't meant to be committed, so fall
+            // back to the cache when there isn't a local copy
+            let local = input
+                .path()
+                .and_then(|path| path.metadata().ok())
+                .is_some_and(|meta| meta.len() > 0);
+            if name == "in" && !local {
+                Input::File(cached_input(day))
+            } else {
+                input
+            }
+        }
+        InputArg::File(path) => Input::File(path.clone()),
+        InputArg::Stdin => Input::Stdin,
+    };
+    if let Some(path) = input.path() {
+        if !path.exists() {
+            eprintln!("error: {} does not exist", path.display());
+            process::exit(1);
+        }
+    }
+
+    return (runner, input);
+}
+
+fn run(args: &Args) {
+    let answers_path = answers::default_path();
+    let mut answers = Answers::load(&answers_path)
+        .unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        });
+    let mut failed = false;
+
+    let days: Vec<(Runner, Input)> =
+        args.days.iter().map(|day| get_day(args, *day)).collect();
+    let reports = run_all(&days, &args.parts);
+
+    for (day, ((_, input), report)) in
+        args.days.iter().copied().zip(days.iter().zip(reports))
+    {
+        let report = match report {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!(
+                    "error: day {:02}, {}: {}",
+                    day, input, e
+                );
+                process::exit(1);
+            }
+        };
+
+        println!("Day {:02} ({})", day, input);
+        println!("  parse: {:?}", report.parse_time);
+        for (part, answer, elapsed) in report.answers {
+            let mut line = format!(
+                "  Part {}: {}  ({:?})",
+                part, answer, elapsed
+            );
+            if let InputArg::Named(name) = &args.input {
+                if args.verify {
+                    let status = match answers
+                        .check(day, name, part, &answer)
+                    {
+                        Status::Pass => "pass".to_string(),
+                        Status::Fail { expected } => {
+                            failed = true;
+                            format!("FAIL, expected {}", expected)
+                        }
+                        Status::Unknown => "unknown".to_string(),
+                    };
+                    line = format!("{}  {}", line, status);
+                }
+                if args.record {
+                    answers.set(day, name, part, &answer);
+                }
+            }
+            println!("{}", line);
+        }
+    }
+
+    if args.record {
+        if let Err(e) = answers.save(&answers_path) {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+        println!(
+            "Recorded answers in {}",
+            answers_path.display()
+        );
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_bench(args: &Args) {
+    let mut benches: Vec<Bench> = Vec::new();
+    for day in args.days.iter().copied() {
+        let (runner, input) = get_day(args, day);
+
+        let b = match bench::bench(
+            day,
+            runner,
+            &input,
+            &args.parts,
+            args.iterations,
+        ) {
+            Ok(b) => b,
+            Err(e) => {
+                eprintln!(
+                    "error: day {:02}, {}: {}",
+                    day, input, e
+                );
+                process::exit(1);
+            }
+        };
+
+        if !args.json {
+            println!(
+                "Day {:02} ({}), {} iterations",
+                b.day, b.input, b.iterations
+            );
+            println!(
+                "  {:<8}{:>14}{:>14}{:>14}",
+                "", "min", "median", "max"
+            );
+            let rows = [("parse".to_string(), b.parse)]
+                .into_iter()
+                .chain(b.parts.iter().map(|(part, stats)| {
+                    (format!("part {}", part), *stats)
+                }));
+            for (name, stats) in rows {
+                println!(
+                    "  {:<8}{:>14}{:>14}{:>14}",
+                    name,
+                    format!("{:?}", stats.min),
+                    format!("{:?}", stats.median),
+                    format!("{:?}", stats.max)
+                );
+            }
+        }
+        benches.push(b);
+    }
+
+    if args.json {
+        println!("{}", bench::to_json(&benches));
+    }
+}
+
+fn run_fetch(args: &Args) {
+    let cache = input_cache();
+    for day in args.days.iter().copied() {
+        match cache.get(fetch::YEAR, day) {
+            Ok(path) => {
+                println!("Day {:02}: {}", day, path.display())
+            }
+            Err(e) => {
+                eprintln!("error: day {:02}: {}", day, e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn run_submit(args: &Args) {
+    let day = args.days[0];
+    let part = args.parts[0];
+    let (runner, input) = get_day(args, day);
+    let fail = |e: &dyn std::fmt::Display| -> ! {
+        eprintln!("error: day {:02}, {}: {}", day, input, e);
+        process::exit(1);
+    };
+
+    let report =
+        runner(&input, &[part]).unwrap_or_else(|e| fail(&e));
+    let answer = &report.answers[0].1;
+    println!("Day {:02} part {}: {}", day, part, answer);
+
+    let log_path = submit::default_log_path(fetch::YEAR);
+    let mut log = SubmissionLog::load(&log_path)
+        .unwrap_or_else(|e| fail(&e));
+    let submitter = HttpSubmitter::from_env();
+    let response = submit::submit_answer(
+        &submitter,
+        &mut log,
+        fetch::YEAR,
+        day,
+        part,
+        answer,
+    )
+    .unwrap_or_else(|e| fail(&e));
+    if let Err(e) = log.save(&log_path) {
+        fail(&e);
+    }
+
+    println!("  {}", response.verdict);
+    if let Some(wait) = response.wait {
+        println!(
+            "  wait {}s before submitting again",
+            wait.as_secs()
+        );
+    }
+    if response.verdict != Verdict::Correct {
+        process::exit(1);
+    }
+
+    let answers_path = answers::default_path();
+    let mut answers =
+        Answers::load(&answers_path).unwrap_or_else(|e| fail(&e));
+    answers.set(day, "in", part, answer);
+    if let Err(e) = answers.save(&answers_path) {
+        fail(&e);
+    }
+    println!("Recorded answer in {}", answers_path.display());
+}
+
+fn run_bag(args: &Args) {
+    let (_, input) = get_day(args, 2);
+    let fail = |e: &dyn std::fmt::Display| -> ! {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    };
+
+    let bag = match (&args.bag, &args.bag_file) {
+        (Some(cubes), _) => cubes
+            .parse::<CubeSet>()
+            .unwrap_or_else(|e| fail(&format!("--bag: {}", e))),
+        (None, Some(path)) => {
+            day02::load_bag(path).unwrap_or_else(|e| fail(&e))
+        }
+        (None, None) => day02::BAG,
+    };
+    let games = Day02::parse(&input)
+        .unwrap_or_else(|e| fail(&format!("{}: {}", input, e)));
+    println!("{}", BagReport::new(&games, &bag));
+}
+
+fn new_day(args: &[String]) {
+    let day = match args {
+        [day] => day.parse::<u32>().ok(),
+        _ => None,
+    };
+    let Some(day) = day else {
+        eprintln!(
+            "error: new-day needs a day number\n\n{}",
+            USAGE
+        );
+        process::exit(2);
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    match scaffold::new_day(root, day) {
+        Ok(touched) => {
+            for path in touched {
+                println!("  {}", path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let raw_args: Vec<String> = env::args().skip(1).collect();
+    if raw_args.iter().any(|x| x == "-h" || x == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+
+    if raw_args.first().is_some_and(|x| x == "new-day") {
+        new_day(&raw_args[1..]);
+        return;
+    }
+
+    let args = match parse_args(&raw_args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match args.command {
+        Command::Run => run(&args),
+        Command::Bench => run_bench(&args),
+        Command::Fetch => run_fetch(&args),
+        Command::Submit => run_submit(&args),
+        Command::Bag => run_bag(&args),
+    }
 }
@@ -0,0 +1,20 @@
+//! Map over independent items, on rayon's thread pool when the
+//! `parallel` feature is on and one after the other when it isn't.
+//! Results always come back in the order of the items, so callers
+//! see the same answers either way.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// `f` applied to each of `items`, in order.
+pub fn map<T, U, F>(items: &[T], f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return items.par_iter().map(f).collect();
+    #[cfg(not(feature = "parallel"))]
+    return items.iter().map(f).collect();
+}
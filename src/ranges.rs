@@ -0,0 +1,390 @@
+use std::ops::{Add, Range, Sub};
+
+use num::Bounded;
+
+/// A set of values stored as sorted, disjoint, non-adjacent
+/// half-open ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        return RangeSet { ranges: Vec::new() };
+    }
+}
+
+impl<T: Ord + Copy> RangeSet<T> {
+    pub fn new() -> Self {
+        return RangeSet::default();
+    }
+
+    /// Sort, drop empty ranges and merge the ones that overlap or
+    /// touch.
+    fn normalize(mut ranges: Vec<Range<T>>) -> Vec<Range<T>> {
+        ranges.retain(|x| x.start < x.end);
+        ranges.sort_by_key(|x| x.start);
+
+        let mut merged: Vec<Range<T>> = Vec::new();
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => {
+                    last.end = last.end.max(range.end);
+                }
+                _ => merged.push(range),
+            }
+        }
+        return merged;
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        self.ranges = RangeSet::normalize(ranges);
+    }
+
+    /// The ranges in order, none overlapping or touching.
+    pub fn ranges(&self) -> &[Range<T>] {
+        return &self.ranges;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.ranges.is_empty();
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        return self.ranges.first().map(|x| x.start);
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let i = self.ranges.partition_point(|x| x.end <= *value);
+        return self
+            .ranges
+            .get(i)
+            .is_some_and(|x| x.start <= *value);
+    }
+
+    /// Whether every value of `range` is in the set.
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.start >= range.end {
+            return true;
+        }
+        let i =
+            self.ranges.partition_point(|x| x.end <= range.start);
+        return self.ranges.get(i).is_some_and(|x| {
+            x.start <= range.start && range.end <= x.end
+        });
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let ranges = self
+            .ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned();
+        return RangeSet {
+            ranges: RangeSet::normalize(ranges.collect()),
+        };
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // whichever ends first can't overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        return RangeSet { ranges };
+    }
+
+    /// Values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in self.ranges.iter() {
+            let mut start = range.start;
+            // skip what ends before this range
+            while j < other.ranges.len()
+                && other.ranges[j].end <= start
+            {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len()
+                && other.ranges[k].start < range.end
+            {
+                let hole = &other.ranges[k];
+                if start < hole.start {
+                    ranges.push(start..hole.start);
+                }
+                start = start.max(hole.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        return RangeSet { ranges };
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(
+        iter: I,
+    ) -> Self {
+        return RangeSet {
+            ranges: RangeSet::normalize(
+                iter.into_iter().collect(),
+            ),
+        };
+    }
+}
+
+/// A map that moves some source ranges to start somewhere else and
+/// leaves every other value where it is, like the almanac's maps.
+/// Sources are kept sorted and never overlap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PiecewiseMap<T> {
+    /// Source ranges and where they start after mapping, sorted by
+    /// source.
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for PiecewiseMap<T> {
+    fn default() -> Self {
+        return PiecewiseMap { pieces: Vec::new() };
+    }
+}
+
+/// Where `value` lands when the range starting at `from` moves to
+/// start at `to`. Stretches that stay put can span the whole type,
+/// so they skip the arithmetic.
+fn shift<T>(value: T, from: T, to: T) -> T
+where
+    T: Ord + Copy + Add<Output = T> + Sub<Output = T>,
+{
+    if from == to {
+        return value;
+    }
+    return to + (value - from);
+}
+
+impl<T> PiecewiseMap<T>
+where
+    T: Ord + Copy + Add<Output = T> + Sub<Output = T> + Bounded,
+{
+    pub fn new() -> Self {
+        return PiecewiseMap::default();
+    }
+
+    /// The map that leaves everything where it is.
+    pub fn identity() -> Self {
+        return PiecewiseMap::default();
+    }
+
+    /// Map `source` onto the range starting at `destination`. Fails
+    /// with the source range it would overlap, if any.
+    pub fn insert(
+        &mut self,
+        source: Range<T>,
+        destination: T,
+    ) -> std::result::Result<(), Range<T>> {
+        if source.start >= source.end {
+            return Ok(());
+        }
+        let i = self
+            .pieces
+            .partition_point(|(x, _)| x.end <= source.start);
+        if let Some((piece, _)) = self.pieces.get(i) {
+            if piece.start < source.end {
+                return Err(piece.clone());
+            }
+        }
+        // pieces that don't move anything are kept too, so later
+        // ranges can't overlap them
+        self.pieces.insert(i, (source, destination));
+        return Ok(());
+    }
+
+    /// The source ranges and where they start after mapping, in
+    /// order.
+    pub fn pieces(&self) -> &[(Range<T>, T)] {
+        return &self.pieces;
+    }
+
+    pub fn map(&self, value: T) -> T {
+        let i =
+            self.pieces.partition_point(|(x, _)| x.end <= value);
+        return match self.pieces.get(i) {
+            Some((source, destination))
+                if source.start <= value =>
+            {
+                shift(value, source.start, *destination)
+            }
+            _ => value,
+        };
+    }
+
+    /// The images of `range`, split wherever it crosses into or out
+    /// of a source range, in source order.
+    pub fn map_range(&self, range: &Range<T>) -> Vec<Range<T>> {
+        let mut images = Vec::new();
+        let mut start = range.start;
+        for (source, destination) in self.pieces.iter() {
+            if source.end <= start {
+                continue;
+            }
+            if source.start >= range.end {
+                break;
+            }
+            if start < source.start {
+                images.push(start..source.start);
+                start = source.start;
+            }
+            let end = source.end.min(range.end);
+            let image = |x| shift(x, source.start, *destination);
+            images.push(image(start)..image(end));
+            start = end;
+        }
+        if start < range.end {
+            images.push(start..range.end);
+        }
+        return images;
+    }
+
+    /// Where every value of `set` ends up.
+    pub fn map_set(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        return set
+            .ranges()
+            .iter()
+            .flat_map(|range| self.map_range(range))
+            .collect();
+    }
+
+    /// Every value from the smallest `T` to the largest, split at
+    /// each breakpoint, including the stretches that don't move.
+    fn segments(&self) -> Vec<(Range<T>, T)> {
+        let mut segments = Vec::new();
+        let mut start = T::min_value();
+        for (source, destination) in self.pieces.iter() {
+            if start < source.start {
+                segments.push((start..source.start, start));
+            }
+            segments.push((source.clone(), *destination));
+            start = source.end;
+        }
+        if start < T::max_value() {
+            segments.push((start..T::max_value(), start));
+        }
+        return segments;
+    }
+
+    /// Build a map back from segments, dropping the ones that
+    /// don't move anything and merging neighbours that move by the
+    /// same amount, so equal compositions compare equal.
+    fn from_segments(segments: Vec<(Range<T>, T)>) -> Self {
+        let mut pieces: Vec<(Range<T>, T)> = Vec::new();
+        for (source, destination) in segments {
+            if destination == source.start
+                || source.start >= source.end
+            {
+                continue;
+            }
+            match pieces.last_mut() {
+                Some((last, last_destination))
+                    if last.end == source.start
+                        && *last_destination
+                            + (last.end - last.start)
+                            == destination =>
+                {
+                    last.end = source.end;
+                }
+                _ => pieces.push((source, destination)),
+            }
+        }
+        return PiecewiseMap { pieces };
+    }
+
+    /// This map followed by `next`, as a single map with as few
+    /// pieces as possible.
+    pub fn then(
+        &self,
+        next: &PiecewiseMap<T>,
+    ) -> PiecewiseMap<T> {
+        let next_segments = next.segments();
+        let mut segments = Vec::new();
+        for (source, destination) in self.segments() {
+            let image = destination
+                ..shift(source.end, source.start, destination);
+            let first = next_segments
+                .partition_point(|(x, _)| x.end <= image.start);
+            for (n, n_destination) in
+                next_segments[first..].iter()
+            {
+                if n.start >= image.end {
+                    break;
+                }
+                let start = n.start.max(image.start);
+                let end = n.end.min(image.end);
+                let back =
+                    |x| shift(x, image.start, source.start);
+                segments.push((
+                    back(start)..back(end),
+                    shift(start, n.start, *n_destination),
+                ));
+            }
+        }
+        return PiecewiseMap::from_segments(segments);
+    }
+
+    /// The map going the other way, if this one is a bijection:
+    /// moved ranges can't land on each other, or on values that
+    /// stay put.
+    pub fn invert(&self) -> Option<PiecewiseMap<T>> {
+        let image = |(source, destination): &(Range<T>, T)| {
+            *destination
+                ..*destination + (source.end - source.start)
+        };
+        let sources: RangeSet<T> =
+            self.pieces.iter().map(|x| x.0.clone()).collect();
+        let images: RangeSet<T> =
+            self.pieces.iter().map(image).collect();
+        let mut inverse = PiecewiseMap::identity();
+        for piece in self.pieces.iter() {
+            inverse.insert(image(piece), piece.0.start).ok()?;
+        }
+        if sources != images {
+            return None;
+        }
+        return Some(inverse);
+    }
+
+    /// The lowest value any of `values` maps to, found by checking
+    /// the start of each stretch between breakpoints.
+    pub fn lowest(&self, values: &RangeSet<T>) -> Option<T> {
+        return self
+            .segments()
+            .into_iter()
+            .filter_map(|(source, destination)| {
+                let inside = values.intersection(
+                    &[source.clone()].into_iter().collect(),
+                );
+                inside
+                    .min()
+                    .map(|x| shift(x, source.start, destination))
+            })
+            .min();
+    }
+}
@@ -0,0 +1,38 @@
+use crate::error::Result;
+use crate::input::Input;
+use crate::parallel;
+use crate::solution::{solve, Part, Report};
+
+pub type Runner = fn(&Input, &[Part]) -> Result<Report>;
+
+/// Every implemented day, in order.
+pub const DAYS: &[(u32, Runner)] = &[
+    (1, solve::<crate::day01::Day01>),
+    (2, solve::<crate::day02::Day02>),
+    (3, solve::<crate::day03::Day03>),
+    (4, solve::<crate::day04::Day04>),
+    (5, solve::<crate::day05::Day05>),
+    (6, solve::<crate::day06::Day06>),
+    (7, solve::<crate::day07::Day07>),
+    (8, solve::<crate::day08::Day08>),
+    (9, solve::<crate::day09::Day09>),
+];
+
+pub fn find_day(day: u32) -> Option<Runner> {
+    return DAYS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, runner)| *runner);
+}
+
+/// Run each day on its input, concurrently with the `parallel`
+/// feature. Reports come back in the order of `days` either way,
+/// though timings include any contention between threads.
+pub fn run_all(
+    days: &[(Runner, Input)],
+    parts: &[Part],
+) -> Vec<Result<Report>> {
+    return parallel::map(days, |(runner, input)| {
+        runner(input, parts)
+    });
+}
@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AocError, Result};
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+fn bad_file(path: &Path, reason: &str) -> AocError {
+    return AocError::BadFile {
+        path: path.to_path_buf(),
+        reason: reason.to_string(),
+    };
+}
+
+/// Insert `entry` before the first registered day after `day`, or
+/// after the last one. `line_day` spots the first line of an entry,
+/// which is `entry_len` lines long.
+fn insert_entry(
+    path: &Path,
+    day: u32,
+    entry: &str,
+    entry_len: usize,
+    line_day: impl Fn(&str) -> Option<u32>,
+) -> Result<String> {
+    let text = fs::read_to_string(path)?;
+    let lines: Vec<&str> = text.lines().collect();
+
+    let registered: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| line_day(line).map(|d| (i, d)))
+        .collect();
+    if registered.iter().any(|(_, d)| *d == day) {
+        return Err(bad_file(path, "day is already registered"));
+    }
+    let position = match registered.iter().find(|(_, d)| *d > day)
+    {
+        Some((i, _)) => *i,
+        None => match registered.last() {
+            Some((i, _)) => i + entry_len,
+            None => {
+                return Err(bad_file(path, "no days to add to"))
+            }
+        },
+    };
+
+    let mut new_lines: Vec<&str> = lines[..position].to_vec();
+    new_lines.extend(entry.lines());
+    new_lines.extend(&lines[position..]);
+    return Ok(new_lines.join("\n") + "\n");
+}
+
+fn stub_test(day: u32) -> String {
+    return format!(
+        r#"#[test]
+#[ignore = "fill in the example answers"]
+fn day{day:02}() {{
+    use aoc23::day{day:02}::Day{day:02};
+    let ex = parse::<Day{day:02}>({day}, "ex");
+    assert_eq!(Day{day:02}::part1(&ex), 0);
+    assert_eq!(Day{day:02}::part2(&ex), 0);
+}}
+"#,
+        day = day
+    );
+}
+
+/// Scaffold day `day` under the repo at `root`: a `src/NNrs` module
+/// from the template, empty inputs, a stub example test, and the
+/// entries in `lib.rs` and the registry. Returns the touched files.
+pub fn new_day(root: &Path, day: u32) -> Result<Vec<PathBuf>> {
+    let day_dir = root.join("src").join(format!("{:02}rs", day));
+    let module = day_dir.join("mod.rs");
+    if !(1..=25).contains(&day) {
+        return Err(bad_file(&day_dir, "days go from 1 to 25"));
+    }
+    if module.exists() {
+        return Err(bad_file(&module, "already exists"));
+    }
+
+    // work out every edit before writing anything, so a failure
+    // doesn't leave a half-registered day behind
+    let lib = root.join("src").join("lib.rs");
+    let lib_text = insert_entry(
+        &lib,
+        day,
+        &format!(
+            "#[path = \"{:02}rs/mod.rs\"]\npub mod day{:02};",
+            day, day
+        ),
+        2,
+        |line| {
+            line.strip_prefix("#[path = \"")?
+                .strip_suffix("rs/mod.rs\"]")?
+                .parse()
+                .ok()
+        },
+    )?;
+
+    let registry = root.join("src").join("registry.rs");
+    let registry_text = insert_entry(
+        &registry,
+        day,
+        &format!(
+            "    ({}, solve::<crate::day{:02}::Day{:02}>),",
+            day, day, day
+        ),
+        1,
+        |line| {
+            let (d, rest) =
+                line.trim().strip_prefix('(')?.split_once(',')?;
+            if !rest.trim().starts_with("solve::<") {
+                return None;
+            }
+            d.parse().ok()
+        },
+    )?;
+
+    let examples = root.join("tests").join("examples.rs");
+    let text = fs::read_to_string(&examples)?;
+    let marker = "/// Check real inputs";
+    let stub = stub_test(day);
+    let examples_text = match text.find(marker) {
+        Some(i) => {
+            format!("{}{}\n{}", &text[..i], stub, &text[i..])
+        }
+        None => format!("{}\n{}", text, stub),
+    };
+
+    fs::write(&lib, lib_text)?;
+    fs::write(&registry, registry_text)?;
+    fs::write(&examples, examples_text)?;
+    fs::create_dir_all(&day_dir)?;
+    fs::write(
+        &module,
+        TEMPLATE.replace("DayNN", &format!("Day{:02}", day)),
+    )?;
+    let mut touched = vec![module];
+    for name in ["ex.in.txt", "in.txt"] {
+        let path = day_dir.join(name);
+        if !path.exists() {
+            fs::write(&path, "")?;
+            touched.push(path);
+        }
+    }
+    touched.extend([lib, registry, examples]);
+    return Ok(touched);
+}
@@ -0,0 +1,86 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::answer::IntoAnswer;
+use crate::error::Result;
+use crate::input::Input;
+
+/// A day's puzzle: parse the input once, then solve both parts
+/// from the parsed value.
+pub trait Solution {
+    type Parsed;
+    type Answer: IntoAnswer;
+
+    fn parse(input: &Input) -> Result<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed) -> Self::Answer;
+
+    fn part2(parsed: &Self::Parsed) -> Self::Answer;
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, got {:?}", s)),
+        }
+    }
+}
+
+/// Answers for one run of a day, with how long each step took.
+#[derive(Debug)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub answers: Vec<(Part, String, Duration)>,
+}
+
+/// Parse `input` once and solve the requested parts, timing each step.
+pub fn solve<S: Solution>(
+    input: &Input,
+    parts: &[Part],
+) -> Result<Report> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let mut answers = Vec::new();
+    for part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&parsed).into_answer()?,
+            Part::Two => S::part2(&parsed).into_answer()?,
+        };
+        let answer = answer.to_string();
+        answers.push((*part, answer, start.elapsed()));
+    }
+
+    return Ok(Report {
+        parse_time,
+        answers,
+    });
+}
@@ -0,0 +1,566 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Write};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+
+use crate::error::{AocError, Result};
+use crate::fetch::{self, NO_SESSION};
+use crate::solution::Part;
+
+const HEADER: &str =
+    "# Answers submitted to adventofcode.com, and what it said.\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the last answer, it wasn't checked.
+    TooSoon,
+    /// The part is already solved, or part 1 isn't yet.
+    WrongLevel,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Verdict::Correct => "that's the right answer",
+            Verdict::Wrong => "that's not the right answer",
+            Verdict::TooHigh => {
+                "that's not the right answer, too high"
+            }
+            Verdict::TooLow => {
+                "that's not the right answer, too low"
+            }
+            Verdict::TooSoon => "answered too recently",
+            Verdict::WrongLevel => {
+                "not the right level, already solved?"
+            }
+        };
+        return write!(f, "{}", s);
+    }
+}
+
+/// What the site said about an answer, and how long it wants us to
+/// wait before the next one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+}
+
+fn strip_tags(html: &str) -> String {
+    let article =
+        match (html.find("<article"), html.find("</article>")) {
+            (Some(start), Some(end)) if start < end => {
+                &html[start..end]
+            }
+            _ => html,
+        };
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let spaces = Regex::new(r"\s+").unwrap();
+    let text = tags.replace_all(article, "");
+    return spaces.replace_all(&text, " ").trim().to_string();
+}
+
+/// Work out the verdict from the page the site answers with.
+pub fn parse_response(html: &str) -> Result<Response> {
+    let text = strip_tags(html);
+
+    if text.contains("That's the right answer") {
+        return Ok(Response {
+            verdict: Verdict::Correct,
+            wait: None,
+        });
+    }
+    if text.contains("You gave an answer too recently") {
+        // "You have 4m 30s left to wait", or just "30s"
+        let left = Regex::new(
+            r"You have (?:(\d+)m)? ?(?:(\d+)s)? left to wait",
+        )
+        .unwrap();
+        let wait = left.captures(&text).map(|caps| {
+            let n = |i| {
+                caps.get(i)
+                    .and_then(|m| m.as_str().parse::<u64>().ok())
+                    .unwrap_or(0)
+            };
+            Duration::from_secs(n(1) * 60 + n(2))
+        });
+        return Ok(Response {
+            verdict: Verdict::TooSoon,
+            wait,
+        });
+    }
+    if text.contains("That's not the right answer") {
+        let verdict;
+        if text.contains("too high") {
+            verdict = Verdict::TooHigh;
+        } else if text.contains("too low") {
+            verdict = Verdict::TooLow;
+        } else {
+            verdict = Verdict::Wrong;
+        }
+        // wrong answers come with a cooldown that grows each time
+        let cooldown =
+            Regex::new(r"(?i)please wait (one|\d+) minutes?")
+                .unwrap();
+        let wait = cooldown.captures(&text).map(|caps| {
+            let minutes = match &caps[1] {
+                "one" => 1,
+                n => n.parse::<u64>().unwrap(),
+            };
+            Duration::from_secs(minutes * 60)
+        });
+        return Ok(Response { verdict, wait });
+    }
+    if text
+        .contains("You don't seem to be solving the right level")
+    {
+        return Ok(Response {
+            verdict: Verdict::WrongLevel,
+            wait: None,
+        });
+    }
+
+    let snippet: String = text.chars().take(200).collect();
+    return Err(AocError::Submit(format!(
+        "unexpected response: {:?}",
+        snippet
+    )));
+}
+
+/// Somewhere answers can be submitted to.
+pub trait Submitter {
+    fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &str,
+    ) -> Result<Response>;
+}
+
+/// Submits answers to the Advent of Code site, logged in with the
+/// `session` cookie.
+pub struct HttpSubmitter {
+    base_url: String,
+    // only needed once an answer gets past the log, so refusals
+    // work without one
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl HttpSubmitter {
+    pub fn new(session: &str) -> Self {
+        return HttpSubmitter {
+            base_url: fetch::BASE_URL.to_string(),
+            session: Some(session.to_string()),
+            agent: fetch::agent(),
+        };
+    }
+
+    /// Use the session token in `AOC_SESSION`. Without one every
+    /// submission fails, but nothing does until one is tried.
+    pub fn from_env() -> Self {
+        let mut submitter = HttpSubmitter::new("");
+        submitter.session = fetch::session_from_env();
+        return submitter;
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url =
+            base_url.trim_end_matches('/').to_string();
+        return self;
+    }
+}
+
+impl Submitter for HttpSubmitter {
+    fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &str,
+    ) -> Result<Response> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url, year, day
+        );
+        let Some(session) = &self.session else {
+            return Err(AocError::Submit(NO_SESSION.to_string()));
+        };
+        // never retried, a repeated answer can cost us a cooldown
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", session))
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", answer),
+            ]);
+        let html = match response {
+            Ok(response) => {
+                response.into_string().map_err(|e| {
+                    AocError::Submit(format!("{}: {}", url, e))
+                })?
+            }
+            Err(ureq::Error::Status(code, _)) => {
+                return Err(AocError::Submit(format!(
+                    "{}: HTTP {}",
+                    url, code
+                )));
+            }
+            Err(e) => {
+                return Err(AocError::Submit(format!(
+                    "{}: {}",
+                    url, e
+                )));
+            }
+        };
+        return parse_response(&html);
+    }
+}
+
+/// What we know about one part from past submissions.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PartLog {
+    pub correct: Option<String>,
+    pub wrong: Vec<String>,
+    /// The lowest answer known to be too high.
+    pub too_high: Option<String>,
+    /// The highest answer known to be too low.
+    pub too_low: Option<String>,
+}
+
+/// Why an answer isn't worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Solved { answer: String },
+    KnownWrong,
+    TooHigh { bound: String },
+    TooLow { bound: String },
+    Wait(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Refusal::Solved { answer } => {
+                write!(f, "already solved with {}", answer)
+            }
+            Refusal::KnownWrong => {
+                write!(f, "already submitted, and it was wrong")
+            }
+            Refusal::TooHigh { bound } => {
+                write!(f, "{} was already too high", bound)
+            }
+            Refusal::TooLow { bound } => {
+                write!(f, "{} was already too low", bound)
+            }
+            Refusal::Wait(wait) => write!(
+                f,
+                "the site wants us to wait another {}s",
+                wait.as_secs()
+            ),
+        };
+    }
+}
+
+/// Every answer we've submitted, stored in `submissions.toml` as
+///
+/// ```toml
+/// wait_until = 1701417600
+///
+/// [day05.part1]
+/// wrong = ["12", "9000"]
+/// too_high = "9000"
+/// ```
+#[derive(Debug, Default)]
+pub struct SubmissionLog {
+    /// Unix time before which the site won't take another answer.
+    wait_until: Option<u64>,
+    parts: BTreeMap<(u32, Part), PartLog>,
+}
+
+/// `submissions.toml` for `year` in the input cache directory.
+pub fn default_log_path(year: u32) -> PathBuf {
+    return fetch::default_cache_dir()
+        .join(year.to_string())
+        .join("submissions.toml");
+}
+
+/// `text` as a toml string, escaped the way toml wants.
+fn toml_string(text: &str) -> String {
+    return toml::Value::String(text.to_string()).to_string();
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    return time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+}
+
+fn bad_file(path: &Path, reason: String) -> AocError {
+    return AocError::BadFile {
+        path: path.to_path_buf(),
+        reason,
+    };
+}
+
+fn as_number(answer: &str) -> Option<i128> {
+    return answer.trim().parse().ok();
+}
+
+/// Whether `answer` makes a better bound than `bound`.
+fn tighter(
+    bound: &Option<String>,
+    answer: &str,
+    better: impl Fn(i128, i128) -> bool,
+) -> bool {
+    let Some(bound) = bound else {
+        return true;
+    };
+    return match (as_number(answer), as_number(bound)) {
+        (Some(n), Some(b)) => better(n, b),
+        (Some(_), None) => true,
+        (None, _) => false,
+    };
+}
+
+impl SubmissionLog {
+    /// Load the log from `path`, a missing file is an empty log.
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut log = SubmissionLog::default();
+        if !path.exists() {
+            return Ok(log);
+        }
+
+        let table: toml::Table = fs::read_to_string(path)?
+            .parse()
+            .map_err(|e: toml::de::Error| {
+                bad_file(path, e.message().to_string())
+            })?;
+        let string = |value: &toml::Value, key: &str| {
+            return value
+                .as_str()
+                .map(|x| x.to_string())
+                .ok_or_else(|| {
+                    bad_file(
+                        path,
+                        format!("{} is not a string", key),
+                    )
+                });
+        };
+        for (key, value) in table.iter() {
+            if key == "wait_until" {
+                let secs = value
+                    .as_integer()
+                    .and_then(|x| u64::try_from(x).ok())
+                    .ok_or_else(|| {
+                        bad_file(
+                            path,
+                            "wait_until is not a time"
+                                .to_string(),
+                        )
+                    })?;
+                log.wait_until = Some(secs);
+                continue;
+            }
+            let day = key
+                .strip_prefix("day")
+                .and_then(|x| x.parse::<u32>().ok())
+                .ok_or_else(|| {
+                    bad_file(path, format!("bad day {:?}", key))
+                })?;
+            for part in Part::ALL {
+                let part_key = format!("part{}", part);
+                let Some(entry) = value.get(&part_key) else {
+                    continue;
+                };
+                let name = format!("{}.{}", key, part_key);
+                let field = |field: &str| {
+                    return entry
+                        .get(field)
+                        .map(|x| string(x, &name))
+                        .transpose();
+                };
+                let wrong = match entry.get("wrong") {
+                    None => Vec::new(),
+                    Some(list) => list
+                        .as_array()
+                        .ok_or_else(|| {
+                            bad_file(
+                                path,
+                                format!(
+                                    "{}.wrong is not a list",
+                                    name
+                                ),
+                            )
+                        })?
+                        .iter()
+                        .map(|x| string(x, &name))
+                        .collect::<Result<Vec<_>>>()?,
+                };
+                log.parts.insert(
+                    (day, part),
+                    PartLog {
+                        correct: field("correct")?,
+                        wrong,
+                        too_high: field("too_high")?,
+                        too_low: field("too_low")?,
+                    },
+                );
+            }
+        }
+        return Ok(log);
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut s = HEADER.to_string();
+        if let Some(secs) = self.wait_until {
+            writeln!(s, "wait_until = {}", secs).unwrap();
+        }
+        for ((day, part), log) in self.parts.iter() {
+            writeln!(s, "\n[day{:02}.part{}]", day, part)
+                .unwrap();
+            if let Some(answer) = &log.correct {
+                writeln!(s, "correct = {}", toml_string(answer))
+                    .unwrap();
+            }
+            if !log.wrong.is_empty() {
+                let wrong: Vec<String> = log
+                    .wrong
+                    .iter()
+                    .map(|x| toml_string(x))
+                    .collect();
+                writeln!(s, "wrong = [{}]", wrong.join(", "))
+                    .unwrap();
+            }
+            if let Some(bound) = &log.too_high {
+                writeln!(s, "too_high = {}", toml_string(bound))
+                    .unwrap();
+            }
+            if let Some(bound) = &log.too_low {
+                writeln!(s, "too_low = {}", toml_string(bound))
+                    .unwrap();
+            }
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, s)?;
+        return Ok(());
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&PartLog> {
+        return self.parts.get(&(day, part));
+    }
+
+    /// Why `answer` shouldn't be submitted at `now`, if it shouldn't.
+    pub fn check(
+        &self,
+        day: u32,
+        part: Part,
+        answer: &str,
+        now: SystemTime,
+    ) -> Option<Refusal> {
+        if let Some(log) = self.get(day, part) {
+            if let Some(correct) = &log.correct {
+                return Some(Refusal::Solved {
+                    answer: correct.clone(),
+                });
+            }
+            if log.wrong.iter().any(|x| x == answer) {
+                return Some(Refusal::KnownWrong);
+            }
+            let n = as_number(answer);
+            if let (Some(n), Some(bound)) = (n, &log.too_high) {
+                if as_number(bound).is_some_and(|b| n >= b) {
+                    return Some(Refusal::TooHigh {
+                        bound: bound.clone(),
+                    });
+                }
+            }
+            if let (Some(n), Some(bound)) = (n, &log.too_low) {
+                if as_number(bound).is_some_and(|b| n <= b) {
+                    return Some(Refusal::TooLow {
+                        bound: bound.clone(),
+                    });
+                }
+            }
+        }
+
+        let now = unix_secs(now);
+        if let Some(until) = self.wait_until.filter(|x| *x > now)
+        {
+            return Some(Refusal::Wait(Duration::from_secs(
+                until - now,
+            )));
+        }
+        return None;
+    }
+
+    /// Remember what the site said about `answer`.
+    pub fn record(
+        &mut self,
+        day: u32,
+        part: Part,
+        answer: &str,
+        response: &Response,
+        now: SystemTime,
+    ) {
+        if let Some(wait) = response.wait {
+            self.wait_until =
+                Some(unix_secs(now) + wait.as_secs());
+        }
+
+        let log = self.parts.entry((day, part)).or_default();
+        let answer = answer.to_string();
+        match response.verdict {
+            Verdict::Correct => log.correct = Some(answer),
+            Verdict::Wrong => log.wrong.push(answer),
+            Verdict::TooHigh => {
+                if tighter(&log.too_high, &answer, |n, b| n < b) {
+                    log.too_high = Some(answer.clone());
+                }
+                log.wrong.push(answer);
+            }
+            Verdict::TooLow => {
+                if tighter(&log.too_low, &answer, |n, b| n > b) {
+                    log.too_low = Some(answer.clone());
+                }
+                log.wrong.push(answer);
+            }
+            // the answer wasn't checked, so there's nothing to learn
+            Verdict::TooSoon | Verdict::WrongLevel => {}
+        }
+    }
+}
+
+/// Submit `answer` unless the log already knows it's not worth it,
+/// then log the verdict.
+pub fn submit_answer<S: Submitter>(
+    submitter: &S,
+    log: &mut SubmissionLog,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<Response> {
+    let now = SystemTime::now();
+    if let Some(refusal) = log.check(day, part, answer, now) {
+        return Err(AocError::Submit(format!(
+            "not submitting {}: {}",
+            answer, refusal
+        )));
+    }
+    let response = submitter.submit(year, day, part, answer)?;
+    log.record(day, part, answer, &response, now);
+    return Ok(response);
+}
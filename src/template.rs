@@ -1,36 +1,24 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use crate::{Input, Result, Solution};
 
-fn read_lines<P>(
-    filename: P,
-) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-fn parse(path: &Path) {
-    if let Ok(lines) = read_lines(path) {
-    } else {
-        panic!();
-    }
-    return;
-}
+pub struct DayNN;
 
-fn main() {
-    let path_buf =
-        Path::new(file!()).parent().unwrap().join("ex.in.txt");
-    // let path_buf =
-    //     Path::new(file!()).parent().unwrap().join("in.txt");
+impl Solution for DayNN {
+    type Parsed = Vec<String>;
+    type Answer = i64;
 
-    assert!(path_buf.as_path().exists());
-
-    parse(path_buf.as_path());
+    fn parse(input: &Input) -> Result<Vec<String>> {
+        let mut parsed = Vec::new();
+        for line in input.lines()? {
+            parsed.push(line?.text);
+        }
+        return Ok(parsed);
+    }
 
-    let total = 0;
+    fn part1(_parsed: &Vec<String>) -> i64 {
+        return 0;
+    }
 
-    println!("Total is: {}", total);
+    fn part2(_parsed: &Vec<String>) -> i64 {
+        return 0;
+    }
 }
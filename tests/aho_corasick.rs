@@ -0,0 +1,98 @@
+use aoc23::aho_corasick::AhoCorasick;
+
+/// Each match as `(start, end, value)`.
+fn found<V: Copy>(
+    ac: &AhoCorasick<V>,
+    text: &str,
+) -> Vec<(usize, usize, V)> {
+    return ac
+        .find_iter(text)
+        .map(|x| (x.start, x.end, *x.value))
+        .collect();
+}
+
+#[test]
+fn overlapping_matches() {
+    let ac = AhoCorasick::new([
+        ("he", 1),
+        ("she", 2),
+        ("his", 3),
+        ("hers", 4),
+    ]);
+    assert_eq!(
+        found(&ac, "ushers"),
+        [(1, 4, 2), (2, 4, 1), (2, 6, 4)]
+    );
+    assert_eq!(
+        found(&ac, "ahishe"),
+        [(1, 4, 3), (3, 6, 2), (4, 6, 1)]
+    );
+    assert_eq!(ac.longest(), 4);
+    assert!(found(&ac, "nothing").is_empty());
+}
+
+#[test]
+fn matches_brute_force() {
+    let patterns = ["a", "ab", "bab", "bc", "bca", "c", "caa"];
+    let ac =
+        AhoCorasick::new(patterns.iter().map(|x| (*x, x.len())));
+    let text = "abccab bcabcaab aaa";
+    let mut expected = Vec::new();
+    for end in 1..=text.len() {
+        // longest first, like the automaton
+        for pattern in patterns.iter().rev() {
+            if text[..end].ends_with(pattern) {
+                expected.push((
+                    end - pattern.len(),
+                    end,
+                    pattern.len(),
+                ));
+            }
+        }
+    }
+    expected.sort_by_key(|x| (x.1, std::cmp::Reverse(x.2)));
+    assert_eq!(found(&ac, text), expected);
+}
+
+#[test]
+fn edge_cases() {
+    // empty patterns are ignored rather than matching everywhere
+    let ac = AhoCorasick::new([("", 0), ("aa", 1)]);
+    assert_eq!(found(&ac, "aaa"), [(0, 2, 1), (1, 3, 1)]);
+    assert!(
+        found(&AhoCorasick::<u8>::default(), "abc").is_empty()
+    );
+
+    // positions are bytes
+    let ac = AhoCorasick::new([("zwölf", 12)]);
+    let text = "elf, zwölf";
+    let m = ac.find_all(text)[0];
+    assert_eq!(&text[m.start..m.end], "zwölf");
+
+    // a repeated pattern matches once per value
+    let ac = AhoCorasick::new([("ab", 'x'), ("ab", 'y')]);
+    assert_eq!(found(&ac, "ab"), [(0, 2, 'x'), (0, 2, 'y')]);
+}
+
+#[test]
+fn reversed_reads_from_the_end() {
+    let patterns = ["a", "ab", "bab", "bc", "bca", "c", "caa"];
+    let forward =
+        AhoCorasick::new(patterns.iter().map(|x| (*x, x.len())));
+    let backward = AhoCorasick::reversed(
+        patterns.iter().map(|x| (*x, x.len())),
+    );
+    let text = "abccab bcabcaab aaa";
+    // the same matches, by where they start, last and longest
+    // first
+    let mut expected = found(&forward, text);
+    expected.sort_by_key(|x| {
+        (std::cmp::Reverse(x.0), std::cmp::Reverse(x.1))
+    });
+    assert_eq!(found(&backward, text), expected);
+
+    let ac = AhoCorasick::reversed([("zwölf", 12)]);
+    let text = "zwölf, elf";
+    let m = ac.find_all(text)[0];
+    assert_eq!(&text[m.start..m.end], "zwölf");
+}
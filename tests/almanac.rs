@@ -0,0 +1,120 @@
+use aoc23::day05::{Almanac, Day05};
+use aoc23::{Input, Solution};
+
+fn example() -> Almanac {
+    return Almanac::parse(&Input::day(5, "ex")).unwrap();
+}
+
+#[test]
+fn follows_the_chain() {
+    let almanac = example();
+    let (to, _) = almanac.map("soil").unwrap();
+    assert_eq!(to, "fertilizer");
+    assert!(almanac.map("location").is_none());
+
+    // soil 14 is fertilizer 53, water 49, light 42, temperature 42
+    // and humidity 43 in the puzzle's walkthrough
+    let soil_to_humidity =
+        almanac.resolve("soil", "humidity").unwrap();
+    assert_eq!(soil_to_humidity.map(14), 43);
+    assert_eq!(
+        almanac.chain("soil", "humidity").unwrap().len(),
+        5
+    );
+    assert_eq!(
+        almanac.resolve("water", "water").unwrap().pieces(),
+        []
+    );
+}
+
+#[test]
+fn any_categories_will_do() {
+    let text = "seeds: 1 7\n\n\
+        apple-to-pear map:\n10 0 5\n\n\
+        pear-to-plum map:\n0 10 2\n";
+    let almanac = Almanac::parse(&Input::text(text)).unwrap();
+    let apple_to_plum = almanac.resolve("apple", "plum").unwrap();
+    assert_eq!(apple_to_plum.map(1), 1);
+    assert_eq!(apple_to_plum.map(3), 13);
+    assert_eq!(apple_to_plum.map(7), 7);
+}
+
+#[test]
+fn reports_broken_chains() {
+    let almanac = example();
+    let err = almanac.resolve("humidity", "seed").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "inconsistent input: no map from location, \
+         so humidity never reaches seed"
+    );
+
+    let text = "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\n\
+        soil-to-seed map:\n2 1 3\n";
+    let almanac = Almanac::parse(&Input::text(text)).unwrap();
+    let err = almanac.resolve("seed", "location").unwrap_err();
+    assert!(err.to_string().contains("loop back to seed"));
+
+    // day 05 needs seeds to reach a location
+    let err = Day05::parse(&Input::text(text)).unwrap_err();
+    assert!(err.to_string().starts_with("inconsistent input"));
+}
+
+#[test]
+fn rejects_bad_headers() {
+    let cases = [
+        ("seeds: 1\n10 20 3\n", "line 2: range before any map"),
+        ("seed-to soil map:\n", "line 1: does not match"),
+        (
+            "a-to-b map:\n\na-to-c map:\n",
+            "line 3: a second map from a",
+        ),
+    ];
+    for (text, expected) in cases {
+        let err = Almanac::parse(&Input::text(text)).unwrap_err();
+        assert!(err.to_string().starts_with(expected), "{}", err);
+    }
+}
+
+#[test]
+fn rejects_unusable_seeds() {
+    let maps = "\n\nseed-to-location map:\n1 2 3\n";
+    let cases = [
+        ("seeds:", "inconsistent input: there are no seeds"),
+        (
+            "seeds: 1 2 3",
+            "inconsistent input: 3 seed numbers can't all pair up into ranges",
+        ),
+        (
+            "seeds: 1 2 3 0",
+            "inconsistent input: the seed range from 3 has length 0",
+        ),
+    ];
+    for (seeds, expected) in cases {
+        let text = format!("{}{}", seeds, maps);
+        let err = Day05::parse(&Input::text(text)).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+}
+
+#[test]
+fn rejects_ranges_past_the_largest_number() {
+    let cases = [
+        (
+            "seeds: 9223372036854775800 100\n\nseed-to-location map:\n1 2 3\n",
+            "line 1: the seed range from 9223372036854775800 runs past 9223372036854775807",
+        ),
+        (
+            "seeds: 1 2\n\nseed-to-location map:\n9223372036854775800 0 100\n",
+            "line 4: a range of length 100 runs past 9223372036854775807",
+        ),
+        (
+            "seeds: 1 2\n\nseed-to-location map:\n0 9223372036854775800 100\n",
+            "line 4: a range of length 100 runs past 9223372036854775807",
+        ),
+    ];
+    for (text, expected) in cases {
+        let err = Day05::parse(&Input::text(text)).unwrap_err();
+        assert!(err.to_string().starts_with(expected), "{}", err);
+    }
+}
@@ -0,0 +1,78 @@
+use aoc23::answer::{
+    checked_product, checked_sum, Answer, IntoAnswer,
+};
+use aoc23::solution::{solve, Part};
+use aoc23::{Input, Result, Solution};
+use num_bigint::BigInt;
+
+#[test]
+fn holds_any_integer_or_text() {
+    assert_eq!(Answer::from(-3i32).to_string(), "-3");
+    assert_eq!(
+        Answer::from(u128::MAX).to_string(),
+        u128::MAX.to_string()
+    );
+    let huge = BigInt::from(10).pow(40);
+    assert_eq!(Answer::from(huge.clone()), Answer::Int(huge));
+    assert_eq!(Answer::from("HELLO").to_string(), "HELLO");
+    // the same number compares equal whatever it was worked out in
+    assert_eq!(Answer::from(7u8), Answer::from(7i64));
+}
+
+#[test]
+fn checked_arithmetic() {
+    assert_eq!(checked_sum([1i32, 2, 3]).unwrap(), 6);
+    assert_eq!(checked_product(Vec::<i64>::new()).unwrap(), 1);
+
+    let err = checked_sum([i32::MAX, 1]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "overflow: sum doesn't fit in i32"
+    );
+    let err = checked_product([1u64 << 40, 1 << 40]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "overflow: product doesn't fit in u64"
+    );
+
+    let ok: Result<i64> = Ok(5);
+    assert_eq!(ok.into_answer().unwrap(), Answer::from(5));
+}
+
+/// Sums its lines, in whatever the part asks for.
+struct Sum;
+
+impl Solution for Sum {
+    type Parsed = Vec<i64>;
+    type Answer = Result<Answer>;
+
+    fn parse(input: &Input) -> Result<Vec<i64>> {
+        let mut values = Vec::new();
+        for line in input.lines()? {
+            let line = line?;
+            values.push(line.parse_int(&line.text)?);
+        }
+        return Ok(values);
+    }
+
+    fn part1(values: &Vec<i64>) -> Result<Answer> {
+        return Ok(checked_sum(values.iter().copied())?.into());
+    }
+
+    fn part2(values: &Vec<i64>) -> Result<Answer> {
+        let sum: BigInt =
+            values.iter().map(|x| BigInt::from(*x)).sum();
+        return Ok(sum.into());
+    }
+}
+
+#[test]
+fn overflow_fails_the_run() {
+    let input =
+        Input::text(format!("{}\n{}", i64::MAX, i64::MAX));
+    let err = solve::<Sum>(&input, &[Part::One]).unwrap_err();
+    assert!(err.to_string().starts_with("overflow"));
+
+    let report = solve::<Sum>(&input, &[Part::Two]).unwrap();
+    assert_eq!(report.answers[0].1, "18446744073709551614");
+}
@@ -0,0 +1,27 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use aoc23::answers::Answers;
+use aoc23::solution::Part;
+
+#[test]
+fn odd_input_names_round_trip() {
+    let path = env::temp_dir()
+        .join(format!("aoc23-answers-{}.toml", process::id()));
+    let names =
+        ["in", "ex.small", "ex big", "say \"hi\"", "ex\\2"];
+    let mut answers = Answers::default();
+    for (i, name) in names.iter().enumerate() {
+        answers.set(5, name, Part::One, &i.to_string());
+    }
+    answers.set(5, "in", Part::Two, "a \"quoted\" answer");
+    answers.save(&path).unwrap();
+
+    let loaded = Answers::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let all: Vec<_> = answers.iter().collect();
+    assert_eq!(loaded.iter().collect::<Vec<_>>(), all);
+    assert_eq!(loaded.get(5, "ex.small", Part::One), Some("1"));
+    assert_eq!(loaded.get(5, "ex", Part::One), None);
+}
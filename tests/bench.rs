@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use aoc23::bench::{self, Stats};
+use aoc23::registry::find_day;
+use aoc23::solution::Part;
+use aoc23::Input;
+
+#[test]
+fn stats_median() {
+    let ms = Duration::from_millis;
+
+    let odd = Stats::from_samples(&[ms(3), ms(1), ms(2)]);
+    assert_eq!(odd.min, ms(1));
+    assert_eq!(odd.median, ms(2));
+    assert_eq!(odd.max, ms(3));
+
+    let even = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(8)]);
+    assert_eq!(even.median, ms(3));
+}
+
+#[test]
+fn bench_json() {
+    let runner = find_day(9).unwrap();
+    let input = Input::day(9, "ex");
+    let b =
+        bench::bench(9, runner, &input, &[Part::Two], 3).unwrap();
+    assert_eq!(b.iterations, 3);
+    assert_eq!(b.parts.len(), 1);
+
+    let json = bench::to_json(&[b]);
+    assert!(json.starts_with("[\n  {\"day\": 9, \"input\": "));
+    assert!(json.contains("\"part2\": {\"min_ns\": "));
+    assert!(!json.contains("\"part1\""));
+}
@@ -0,0 +1,86 @@
+use aoc23::day06::{winning_holds, winning_ways, Day06};
+use aoc23::{Input, Solution};
+use num_bigint::BigUint;
+
+/// Every winning hold, by trying them all.
+fn brute_force(time: u64, record: u64) -> Vec<u64> {
+    return (0..=time)
+        .filter(|t| t * (time - t) > record)
+        .collect();
+}
+
+#[test]
+fn example_races() {
+    assert_eq!(winning_holds(&7u64, &9), Some(2..=5));
+    assert_eq!(winning_holds(&15u64, &40), Some(4..=11));
+    // holding 10 or 20 only ties the record
+    assert_eq!(winning_holds(&30u64, &200), Some(11..=19));
+    assert_eq!(winning_ways(&71530u64, &940200), 71503);
+}
+
+#[test]
+fn matches_brute_force() {
+    for time in 0..60u64 {
+        for record in 0..=time * time / 4 + 1 {
+            let expected = brute_force(time, record);
+            let holds: Vec<u64> = winning_holds(&time, &record)
+                .map(|x| x.collect())
+                .unwrap_or_default();
+            assert_eq!(holds, expected, "{} {}", time, record);
+        }
+    }
+}
+
+#[test]
+fn unbeatable_records() {
+    // 2 * 2 only ties
+    assert_eq!(winning_holds(&4u64, &4), None);
+    assert_eq!(winning_ways(&4u64, &4), 0);
+    assert_eq!(winning_holds(&0u64, &0), None);
+    assert_eq!(winning_holds(&3i64, &100), None);
+}
+
+#[test]
+fn wide_integers() {
+    // past 2^53 f64 can't tell neighbouring holds apart
+    let time: u128 = 1 << 60;
+    let record = (time / 2 - 3) * (time / 2 + 3);
+    assert_eq!(
+        winning_holds(&time, &record),
+        Some(time / 2 - 2..=time / 2 + 2)
+    );
+
+    let time = BigUint::from(10u8).pow(40);
+    let half = &time / 2u8;
+    let record = (&half - 7u8) * (&half + 7u8);
+    assert_eq!(winning_ways(&time, &record), BigUint::from(13u8));
+}
+
+#[test]
+fn parses_races_once() {
+    let text = "Time:      7  15   30\nDistance:  9  40  200\n";
+    let races = Day06::parse(&Input::text(text)).unwrap();
+    assert_eq!(races.races, [(7, 9), (15, 40), (30, 200)]);
+    assert_eq!(
+        races.long_race,
+        (BigUint::from(71530u32), BigUint::from(940200u32))
+    );
+
+    // squaring a time this long overflows a u64
+    let text = "Time: 5000000000\nDistance: 9\n";
+    let races = Day06::parse(&Input::text(text)).unwrap();
+    assert_eq!(
+        Day06::part1(&races),
+        BigUint::from(4999999999u64)
+    );
+
+    let text = "Time: 7 15\nDistance: 9\n";
+    let err = Day06::parse(&Input::text(text)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "inconsistent input: 2 times but 1 distances"
+    );
+    let err = Day06::parse(&Input::text("Time:\nDistance:\n"))
+        .unwrap_err();
+    assert!(err.to_string().starts_with("line 1: no numbers"));
+}
@@ -0,0 +1,32 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{self, Command};
+
+/// Run `aoc` with `args` from inside `dir`, and its stdout.
+fn aoc_in(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    return String::from_utf8(output.stdout).unwrap();
+}
+
+#[test]
+fn input_names_are_never_paths() {
+    let dir = env::temp_dir()
+        .join(format!("aoc23-cli-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("ex"), "x9y\n").unwrap();
+
+    let named =
+        aoc_in(&dir, &["--day", "1", "-p", "1", "-i", "ex"]);
+    let path =
+        aoc_in(&dir, &["--day", "1", "-p", "1", "-i", "./ex"]);
+    fs::remove_dir_all(&dir).unwrap();
+    // the example under src/01rs, not the file next to us
+    assert!(named.contains("Part 1: 142 "), "{}", named);
+    assert!(path.contains("Part 1: 99 "), "{}", path);
+}
@@ -0,0 +1,130 @@
+use std::{env, fs, process};
+
+use aoc23::day02::{
+    load_bag, BagReport, Color, CubeSet, Day02, Game,
+};
+use aoc23::{Input, Solution};
+
+#[test]
+fn cube_sets() {
+    let a = CubeSet::new(4, 0, 3);
+    let b = CubeSet::new(1, 2, 6);
+    assert_eq!(a.max(&b), CubeSet::new(4, 2, 6));
+    assert_eq!(a.max(&b).power(), 48);
+    assert_eq!(a[Color::Blue], 3);
+    assert_eq!(
+        CubeSet::default().with(Color::Green, 5)[Color::Green],
+        5
+    );
+
+    let bag = CubeSet::new(12, 13, 14);
+    assert!(a.fits_within(&bag));
+    assert!(!CubeSet::new(20, 8, 6).fits_within(&bag));
+    // big enough that a u64 would overflow
+    let huge = CubeSet::new(u32::MAX, u32::MAX, u32::MAX);
+    assert_eq!(huge.power(), u128::from(u32::MAX).pow(3));
+}
+
+#[test]
+fn games() {
+    let games = Day02::parse(&Input::day(2, "ex")).unwrap();
+    assert_eq!(games.len(), 5);
+    assert_eq!(games[0].id, 1);
+    assert_eq!(games[0].reveals[1], CubeSet::new(1, 2, 6));
+    assert_eq!(games[0].minimum(), CubeSet::new(4, 2, 6));
+    let possible: Vec<u32> = games
+        .iter()
+        .filter(|x| x.possible_with(&CubeSet::new(12, 13, 14)))
+        .map(|x| x.id)
+        .collect();
+    assert_eq!(possible, [1, 2, 5]);
+}
+
+#[test]
+fn rejects_bad_games() {
+    let cases = [
+        ("Game 1: 3 purple", "line 2: unknown color \"purple\""),
+        (
+            "Game 1: 3 blue, 4 blue",
+            "line 2: blue twice in one reveal",
+        ),
+        (
+            "Game 1: 3 blue,4 red",
+            "line 2: unknown color \"blue,4 red\"",
+        ),
+        ("Game 1: 3blue", "line 2: expected a count and a color"),
+        ("Round 1: 3 blue", "line 2: expected \"Game N: ...\""),
+        ("Game one: 3 blue", "line 2:"),
+    ];
+    for (game, expected) in cases {
+        let text = format!("Game 9: 1 red\n{}\n", game);
+        let err = Day02::parse(&Input::text(text)).unwrap_err();
+        assert!(err.to_string().starts_with(expected), "{}", err);
+    }
+
+    let line = aoc23::input::Line {
+        number: 7,
+        text: "Game 2: 1 red; 2 green".to_string(),
+    };
+    assert_eq!(Game::parse(&line).unwrap().reveals.len(), 2);
+}
+
+#[test]
+fn bag_report() {
+    let games = Day02::parse(&Input::day(2, "ex")).unwrap();
+    let report = BagReport::new(
+        &games,
+        &"12 red, 13 green, 14 blue".parse().unwrap(),
+    );
+    assert_eq!(report.possible_id_sum(), 8);
+    assert_eq!(report.power_sum(), 2286);
+    // game 3's first reveal already has 20 red
+    assert_eq!(
+        report.games[2].violation,
+        Some((0, CubeSet::new(20, 8, 6)))
+    );
+    assert_eq!(report.games[3].violation.unwrap().0, 2);
+    assert!(report.games[4].possible());
+
+    // what if there were more red and no blue at all?
+    let bag: CubeSet = "20 red, 13 green".parse().unwrap();
+    let report = BagReport::new(&games, &bag);
+    assert_eq!(report.possible().count(), 0);
+    let report =
+        BagReport::new(&games, &bag.with(Color::Blue, 15));
+    assert_eq!(report.possible_id_sum(), 15);
+
+    let mut doubled = games.clone();
+    doubled.extend(games);
+    let histogram =
+        BagReport::new(&doubled, &bag).power_histogram();
+    assert_eq!(histogram.len(), 5);
+    assert_eq!(histogram[&1560], 2);
+    assert!(report.to_string().contains(
+        "Game 3: needs 20 red, 13 green, 6 blue (power 1560), possible"
+    ));
+}
+
+#[test]
+fn bag_files() {
+    let dir = env::temp_dir()
+        .join(format!("aoc23-bag-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("bag.toml");
+
+    fs::write(&path, "red = 12\nblue = 14\n").unwrap();
+    assert_eq!(load_bag(&path).unwrap(), CubeSet::new(12, 0, 14));
+
+    fs::write(&path, "red = 12\npurple = 1\n").unwrap();
+    let err = load_bag(&path).unwrap_err().to_string();
+    assert!(
+        err.ends_with("bag.toml: unknown color \"purple\""),
+        "{}",
+        err
+    );
+    fs::write(&path, "red = -1\n").unwrap();
+    let err = load_bag(&path).unwrap_err().to_string();
+    assert!(err.ends_with("red should be a count"), "{}", err);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,133 @@
+use aoc23::answers::{self, Answers};
+use aoc23::registry::find_day;
+use aoc23::{day01, day02, day03, day04, day05};
+use aoc23::{day06, day07, day08, day09};
+use aoc23::{Input, Solution};
+
+fn parse<S: Solution>(day: u32, name: &str) -> S::Parsed {
+    return S::parse(&Input::day(day, name)).unwrap();
+}
+
+#[test]
+fn day01() {
+    use day01::Day01;
+    let ex = parse::<Day01>(1, "ex");
+    assert_eq!(Day01::part1(&ex).unwrap(), 142);
+    let ex2 = parse::<Day01>(1, "ex2");
+    assert_eq!(Day01::part2(&ex2).unwrap(), 281);
+    // the second example spells some lines out entirely
+    assert!(Day01::part1(&ex2).is_err());
+}
+
+#[test]
+fn day02() {
+    use day02::Day02;
+    let ex = parse::<Day02>(2, "ex");
+    assert_eq!(Day02::part1(&ex).unwrap(), 8);
+    assert_eq!(Day02::part2(&ex).unwrap(), 2286);
+}
+
+#[test]
+fn day03() {
+    use day03::Day03;
+    let ex = parse::<Day03>(3, "ex");
+    assert_eq!(Day03::part1(&ex).unwrap(), 4361);
+    assert_eq!(Day03::part2(&ex).unwrap(), 467835);
+
+    // too long for an i64, reported against its row
+    let long = Input::text(format!(
+        "{}\n{}*\n",
+        ".".repeat(24),
+        "9".repeat(23)
+    ));
+    let err = Day03::parse(&long).unwrap_err();
+    assert!(err.to_string().starts_with("line 2: bad integer"));
+}
+
+#[test]
+fn day04() {
+    use day04::Day04;
+    let ex = parse::<Day04>(4, "ex");
+    assert_eq!(Day04::part1(&ex).unwrap(), 13u32.into());
+    assert_eq!(Day04::part2(&ex).unwrap(), 30u32.into());
+}
+
+#[test]
+fn day05() {
+    use day05::Day05;
+    let ex = parse::<Day05>(5, "ex");
+    assert_eq!(Day05::part1(&ex), 35);
+    assert_eq!(Day05::part2(&ex), 46);
+}
+
+#[test]
+fn day06() {
+    use day06::Day06;
+    let ex = parse::<Day06>(6, "ex");
+    assert_eq!(Day06::part1(&ex), 288u32.into());
+    assert_eq!(Day06::part2(&ex), 71503u32.into());
+}
+
+#[test]
+fn day07() {
+    use day07::Day07;
+    let ex = parse::<Day07>(7, "ex");
+    assert_eq!(Day07::part1(&ex).unwrap(), 6440);
+    assert_eq!(Day07::part2(&ex).unwrap(), 5905);
+}
+
+#[test]
+fn day08() {
+    use day08::Day08;
+    assert_eq!(
+        Day08::part1(&parse::<Day08>(8, "ex")).unwrap(),
+        6
+    );
+    assert_eq!(
+        Day08::part2(&parse::<Day08>(8, "ex2")).unwrap(),
+        6
+    );
+}
+
+#[test]
+fn day09() {
+    use day09::Day09;
+    let ex = parse::<Day09>(9, "ex");
+    assert_eq!(Day09::part1(&ex).unwrap(), 114);
+    assert_eq!(Day09::part2(&ex).unwrap(), 2);
+
+    // the first difference is already too big for an i64
+    let wide =
+        Input::text("9223372036854775807 -9223372036854775808\n");
+    let wide = Day09::parse(&wide).unwrap();
+    for err in [Day09::part1(&wide), Day09::part2(&wide)] {
+        assert!(err
+            .unwrap_err()
+            .to_string()
+            .starts_with("overflow"));
+    }
+}
+
+/// Check real inputs against `answers.toml`.
+#[test]
+fn known_answers() {
+    let answers =
+        Answers::load(&answers::default_path()).unwrap();
+
+    for (day, name, part, expected) in answers.iter() {
+        let input = Input::day(day, name);
+        if !input.path().unwrap().exists() {
+            // inputs aren't always checked in
+            continue;
+        }
+        let runner = find_day(day).unwrap_or_else(|| {
+            panic!("day {} is not implemented", day)
+        });
+        let report = runner(&input, &[part]).unwrap();
+        assert_eq!(
+            report.answers[0].1, expected,
+            "day {} part {} on {}",
+            day, part, name
+        );
+    }
+}
@@ -0,0 +1,151 @@
+mod support;
+
+use std::cell::Cell;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{self, Command, Output};
+use std::rc::Rc;
+use std::time::Duration;
+
+use aoc23::fetch::{Fetcher, HttpFetcher, InputCache};
+use aoc23::{AocError, Result};
+use support::StubServer;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!(
+        "aoc23-fetch-{}-{}",
+        name,
+        process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    return dir;
+}
+
+fn fetcher(server: &StubServer) -> HttpFetcher {
+    return HttpFetcher::new("abc123")
+        .with_base_url(server.url())
+        .with_retries(2, Duration::from_millis(1));
+}
+
+/// Counts fetches instead of going anywhere.
+struct FakeFetcher {
+    calls: Rc<Cell<u32>>,
+}
+
+impl Fetcher for FakeFetcher {
+    fn fetch(&self, _year: u32, day: u32) -> Result<String> {
+        self.calls.set(self.calls.get() + 1);
+        return Ok(format!("input for day {}\n", day));
+    }
+}
+
+#[test]
+fn fetches_with_session_cookie() {
+    let server = StubServer::start(vec![(200, "1 2 3\n")]);
+    let text = fetcher(&server).fetch(2023, 5).unwrap();
+    assert_eq!(text, "1 2 3\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/5/input");
+    assert_eq!(
+        requests[0].header("cookie"),
+        Some("session=abc123")
+    );
+}
+
+#[test]
+fn retries_server_errors() {
+    let server = StubServer::start(vec![
+        (500, ""),
+        (502, ""),
+        (200, "ok\n"),
+    ]);
+    let text = fetcher(&server).fetch(2023, 1).unwrap();
+    assert_eq!(text, "ok\n");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn gives_up_after_retries() {
+    let server =
+        StubServer::start(vec![(500, ""), (500, ""), (500, "")]);
+    let err = fetcher(&server).fetch(2023, 1).unwrap_err();
+    assert!(matches!(err, AocError::Fetch(_)));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn does_not_retry_client_errors() {
+    let server =
+        StubServer::start(vec![(404, ""), (200, "ok\n")]);
+    let err = fetcher(&server).fetch(2023, 25).unwrap_err();
+    assert!(err.to_string().contains("HTTP 404"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn caches_inputs_on_disk() {
+    let dir = scratch_dir("cache");
+    let calls = Rc::new(Cell::new(0));
+    let fake = FakeFetcher {
+        calls: Rc::clone(&calls),
+    };
+    let cache = InputCache::new(&dir, fake);
+    assert!(!cache.contains(2023, 7));
+
+    let path = cache.get(2023, 7).unwrap();
+    assert_eq!(path, dir.join("2023").join("07.txt"));
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "input for day 7\n"
+    );
+    assert!(cache.contains(2023, 7));
+    assert_eq!(calls.get(), 1);
+
+    // a hit never goes back to the fetcher
+    assert_eq!(cache.get(2023, 7).unwrap(), path);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn failed_fetches_are_not_cached() {
+    let dir = scratch_dir("failed");
+    let server = StubServer::start(vec![(400, "")]);
+    let cache = InputCache::new(&dir, fetcher(&server));
+    assert!(cache.get(2023, 3).is_err());
+    assert!(!cache.contains(2023, 3));
+}
+
+/// Run `aoc` with `AOC_CACHE_DIR` set and no session.
+fn aoc_offline(cache: &PathBuf, args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env("AOC_CACHE_DIR", cache)
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+}
+
+#[test]
+fn cache_hits_need_no_session() {
+    let dir = scratch_dir("offline");
+    fs::create_dir_all(dir.join("2023")).unwrap();
+    fs::write(dir.join("2023").join("01.txt"), "1abc2\n")
+        .unwrap();
+
+    let hit = aoc_offline(&dir, &["fetch", "--day", "1"]);
+    assert!(hit.status.success());
+    let stdout = String::from_utf8(hit.stdout).unwrap();
+    assert!(stdout.starts_with("Day 01: "), "{}", stdout);
+
+    // only a miss has to go to the site
+    let miss = aoc_offline(&dir, &["fetch", "--day", "2"]);
+    assert_eq!(miss.status.code(), Some(1));
+    let stderr = String::from_utf8(miss.stderr).unwrap();
+    assert!(stderr.contains("set AOC_SESSION"), "{}", stderr);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
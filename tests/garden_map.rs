@@ -0,0 +1,81 @@
+use aoc23::day05::{compose, Almanac, Day05, GardenMap};
+use aoc23::ranges::RangeSet;
+use aoc23::{Input, Solution};
+
+/// The seeds, and the example's maps in chain order.
+fn example() -> (Vec<i64>, Vec<GardenMap>) {
+    let almanac = Almanac::parse(&Input::day(5, "ex")).unwrap();
+    let maps = almanac
+        .chain("seed", "location")
+        .unwrap()
+        .into_iter()
+        .cloned()
+        .collect();
+    return (almanac.seeds, maps);
+}
+
+#[test]
+fn rejects_overlapping_sources() {
+    let mut map = GardenMap::identity();
+    map.insert(10..20, 100).unwrap();
+    map.insert(20..25, 20).unwrap();
+    assert_eq!(map.insert(15..30, 0), Err(10..20));
+    // even a range that maps to itself takes up room
+    assert!(map.insert(24..26, 0).is_err());
+    assert_eq!(map.pieces().len(), 2);
+
+    let text =
+        "seeds: 1\n\nseed-to-soil map:\n50 98 2\n10 97 2\n";
+    let err = Day05::parse(&Input::text(text)).unwrap_err();
+    assert!(err.to_string().starts_with("line 5: overlaps"));
+}
+
+#[test]
+fn composes_the_chain() {
+    let (seeds, maps) = example();
+    let seed_to_location = compose(&maps);
+    let locations: Vec<i64> =
+        seeds.iter().map(|x| seed_to_location.map(*x)).collect();
+    assert_eq!(locations, [82, 43, 86, 35]);
+
+    // composing in steps gives the same map
+    let (first, rest) = maps.split_at(3);
+    assert_eq!(
+        compose(first).then(&compose(rest)),
+        seed_to_location
+    );
+
+    // and it's what parsing hands the parts
+    let garden = Day05::parse(&Input::day(5, "ex")).unwrap();
+    assert_eq!(garden.seed_to_location, seed_to_location);
+}
+
+#[test]
+fn inverts() {
+    let (seeds, maps) = example();
+    let seed_to_location = compose(&maps);
+    let location_to_seed = seed_to_location.invert().unwrap();
+    assert_eq!(location_to_seed.map(35), 13);
+    for seed in seeds {
+        let location = seed_to_location.map(seed);
+        assert_eq!(location_to_seed.map(location), seed);
+    }
+    assert_eq!(
+        seed_to_location.then(&location_to_seed),
+        GardenMap::identity()
+    );
+
+    // 0..5 lands on 10..15, which also stays put
+    let mut squash = GardenMap::identity();
+    squash.insert(0..5, 10).unwrap();
+    assert_eq!(squash.invert(), None);
+}
+
+#[test]
+fn lowest_location() {
+    let (_, maps) = example();
+    let seeds: RangeSet<i64> =
+        [79..93, 55..68].into_iter().collect();
+    assert_eq!(compose(&maps).lowest(&seeds), Some(46));
+    assert_eq!(compose(&maps).lowest(&RangeSet::new()), None);
+}
@@ -0,0 +1,50 @@
+use aoc23::geometry::{Direction, Point, Vector};
+
+#[test]
+fn point_arithmetic() {
+    let a = Point::new(1, 2);
+    let b = Point::new(4, -2);
+    assert_eq!(b - a, Vector::new(3, -4));
+    assert_eq!(a + (b - a), b);
+    assert_eq!(b - (b - a), a);
+    assert_eq!(a + Vector::new(1, 1) * 3, Point::new(4, 5));
+
+    let mut c = Point::ORIGIN;
+    c += Vector::new(2, 0);
+    c -= Vector::new(0, 1);
+    assert_eq!(c, Point::new(2, -1));
+}
+
+#[test]
+fn distances() {
+    let a = Point::new(1, 2);
+    let b = Point::new(4, -2);
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(b.manhattan(a), 7);
+    assert_eq!(a.manhattan(a), 0);
+}
+
+#[test]
+fn directions() {
+    use Direction::*;
+
+    assert_eq!(Point::ORIGIN.step(N), Point::new(0, -1));
+    assert_eq!(Point::ORIGIN.step(SE), Point::new(1, 1));
+    assert_eq!(N.turn_right(), E);
+    assert_eq!(N.turn_left(), W);
+    assert_eq!(NE.turn_right(), SE);
+    assert_eq!(SW.reverse(), NE);
+
+    for d in Direction::ALL {
+        assert_eq!(
+            d.vector() + d.reverse().vector(),
+            Vector::new(0, 0)
+        );
+        assert_eq!(d.turn_left().turn_right(), d);
+        assert_eq!(d.vector().chebyshev(), 1);
+    }
+    for d in Direction::CARDINAL {
+        assert_eq!(d.vector().manhattan(), 1);
+    }
+}
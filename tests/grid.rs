@@ -0,0 +1,154 @@
+use aoc23::geometry::{Direction, Point};
+use aoc23::grid::Grid;
+use aoc23::Input;
+
+/// A cell by row and column, to read like the text.
+fn rc(row: i64, col: i64) -> Point {
+    return Point::new(col, row);
+}
+
+fn grid(text: &str) -> Grid<char> {
+    return Grid::parse(&Input::text(text)).unwrap();
+}
+
+fn string<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+    return cells.collect();
+}
+
+#[test]
+fn parses_text() {
+    let g = grid("abc\ndef\n\n");
+    assert_eq!((g.width(), g.height()), (3, 2));
+    assert_eq!(g[rc(1, 2)], 'f');
+    assert_eq!(g.get(rc(2, 0)), None);
+    assert_eq!(g.get(rc(0, 3)), None);
+
+    let err = Grid::parse(&Input::text("abc\nde\n")).unwrap_err();
+    assert!(err.to_string().starts_with("line 2:"));
+
+    let digits = Grid::parse_with(&Input::text("12\n34"), |c| {
+        c.to_digit(10)
+    })
+    .unwrap();
+    assert_eq!(digits[rc(1, 0)], 3);
+    let bad =
+        Grid::parse_with(&Input::text("1x"), |c| c.to_digit(10));
+    assert!(bad.is_err());
+}
+
+#[test]
+fn get_mut_is_bounds_checked() {
+    let mut g = Grid::new(2, 2, 0);
+    *g.get_mut(rc(1, 1)).unwrap() = 5;
+    g[rc(0, 1)] = 2;
+    assert_eq!(g.get_mut(rc(2, 0)), None);
+    assert_eq!(g.to_string(), "02\n05");
+}
+
+#[test]
+fn neighbours() {
+    let g = grid("abc\ndef\nghi");
+    let at = |pos| g[pos];
+    let n4: String = g.neighbours4(rc(1, 1)).map(at).collect();
+    assert_eq!(n4, "bfhd");
+    let n8: String = g.neighbours8(rc(1, 1)).map(at).collect();
+    assert_eq!(n8, "bcfihgda");
+
+    // corners only have the neighbours inside the grid
+    let corner: String =
+        g.neighbours8(rc(0, 0)).map(at).collect();
+    assert_eq!(corner, "bed");
+    assert_eq!(g.neighbours4(rc(2, 2)).count(), 2);
+    // and nothing outside it is inside
+    assert_eq!(g.get(Point::new(-1, 0)), None);
+    assert!(!g.contains(rc(0, -1)));
+}
+
+#[test]
+fn lines_through_the_grid() {
+    let g = grid("abc\ndef");
+    assert_eq!(g.row(1), ['d', 'e', 'f']);
+    assert_eq!(g.rows().count(), 2);
+    assert_eq!(string(g.column(2)), "cf");
+    let columns: Vec<String> = g.columns().map(string).collect();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+
+    assert_eq!(string(g.diagonal(rc(0, 1))), "bf");
+    assert_eq!(string(g.anti_diagonal(rc(0, 2))), "ce");
+    assert_eq!(string(g.ray(rc(1, 2), Direction::W)), "ed");
+    let diagonals: Vec<String> =
+        g.diagonals().map(string).collect();
+    assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+    let anti: Vec<String> =
+        g.anti_diagonals().map(string).collect();
+    assert_eq!(anti, ["a", "bd", "ce", "f"]);
+}
+
+#[test]
+fn transforms() {
+    let g = grid("abc\ndef");
+    assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(g.rotate_right().to_string(), "da\neb\nfc");
+    assert_eq!(g.rotate_left().to_string(), "cf\nbe\nad");
+    assert_eq!(g.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(g.flip_vertical().to_string(), "def\nabc");
+    assert_eq!(g.rotate_right().rotate_left(), g);
+}
+
+#[test]
+fn find_and_map() {
+    let g = grid("#.#\n.#.");
+    let walls: Vec<_> = g.find_all(|x| *x == '#').collect();
+    assert_eq!(walls, [rc(0, 0), rc(0, 2), rc(1, 1)]);
+
+    let numbers = g.map(|x| if *x == '#' { 10 } else { 1 });
+    assert_eq!(numbers.to_string(), "10  1 10\n 1 10  1");
+}
+
+#[test]
+fn spans_stop_at_row_ends() {
+    let g = grid("..12\n3.4.\n56..");
+    let digit = |x: &char| x.is_ascii_digit();
+    let numbers: Vec<String> =
+        g.spans(digit).iter().map(|x| g.span_text(x)).collect();
+    assert_eq!(numbers, ["12", "3", "4", "56"]);
+
+    let spans = g.spans(digit);
+    assert_eq!(spans[0].start, rc(0, 2));
+    assert_eq!(spans[0].len, 2);
+    assert!(spans[3].contains(rc(2, 1)));
+    assert!(!spans[3].contains(rc(2, 2)));
+}
+
+#[test]
+fn span_neighbours() {
+    let g = grid("467..\n...*.\n..35.");
+    let spans = g.spans(|x| x.is_ascii_digit());
+    assert_eq!(
+        spans[0].neighbours(&g),
+        [rc(0, 3), rc(1, 0), rc(1, 1), rc(1, 2), rc(1, 3)]
+    );
+    assert_eq!(spans[0].touching(&g, |x| *x == '*'), [rc(1, 3)]);
+    assert_eq!(spans[1].touching(&g, |x| *x == '*'), [rc(1, 3)]);
+    assert_eq!(spans[1].neighbours(&g).len(), 6);
+}
+
+#[test]
+fn flood_fill_regions() {
+    let g = grid("aab\nabb\nccb");
+    let a = g.flood_fill(rc(0, 0), |x| *x == 'a');
+    assert_eq!(a.len(), 3);
+    assert!(a.contains(rc(1, 0)));
+    assert!(g.flood_fill(rc(0, 2), |x| *x == 'a').is_empty());
+    assert_eq!(
+        a.touching(&g, |x| *x == 'c'),
+        [rc(2, 0), rc(2, 1)]
+    );
+
+    // diagonal cells aren't connected
+    let g = grid("#.#\n.#.\n#.#");
+    assert_eq!(g.regions(|x| *x == '#').len(), 5);
+    let dots = g.regions(|x| *x == '.');
+    assert_eq!(dots.len(), 4);
+    assert!(dots.iter().all(|x| x.len() == 1));
+}
@@ -0,0 +1,53 @@
+use aoc23::day08::Day08;
+use aoc23::{Input, Solution};
+
+fn error(text: &str, part: u32) -> String {
+    let parsed = match Day08::parse(&Input::text(text)) {
+        Ok(parsed) => parsed,
+        Err(e) => return e.to_string(),
+    };
+    let result = match part {
+        1 => Day08::part1(&parsed),
+        _ => Day08::part2(&parsed),
+    };
+    return result.unwrap_err().to_string();
+}
+
+#[test]
+fn reports_missing_nodes() {
+    let ex2 = Day08::parse(&Input::day(8, "ex2")).unwrap();
+    assert_eq!(
+        Day08::part1(&ex2).unwrap_err().to_string(),
+        "inconsistent input: there is no AAA node to start from"
+    );
+
+    let text = "L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+    assert_eq!(
+        error(text, 1),
+        "line 3: unknown node BBB: \"AAA = (BBB, ZZZ)\""
+    );
+    let text = "L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)\n";
+    assert!(error(text, 1).starts_with("line 4: AAA again"));
+
+    let text = "L\n\nZZZ = (ZZZ, ZZZ)\n";
+    assert_eq!(
+        error(text, 2),
+        "inconsistent input: there are no nodes ending in A to start from"
+    );
+}
+
+#[test]
+fn reports_tracks_that_never_end() {
+    let text = "LR\n\nAAA = (BBB, ZZZ)\nBBB = (ZZZ, AAA)\nZZZ = (ZZZ, ZZZ)\n";
+    assert_eq!(
+        error(text, 1),
+        "inconsistent input: AAA goes round in circles without reaching ZZZ"
+    );
+
+    // 11A reaches 11Z, 22A never leaves 22A
+    let text = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22A, 22A)\n";
+    assert_eq!(
+        error(text, 2),
+        "inconsistent input: 22A goes round in circles without reaching a node ending in Z"
+    );
+}
@@ -0,0 +1,46 @@
+use aoc23::parallel;
+use aoc23::registry::{find_day, run_all, Runner};
+use aoc23::solution::Part;
+use aoc23::Input;
+
+#[test]
+fn keeps_the_order() {
+    let items: Vec<u64> = (0..1000).collect();
+    let squares = parallel::map(&items, |x| x * x);
+    assert_eq!(squares.len(), 1000);
+    assert!(squares
+        .iter()
+        .enumerate()
+        .all(|(i, x)| { *x == (i * i) as u64 }));
+}
+
+#[test]
+fn runs_days_in_order() {
+    let days: Vec<(Runner, Input)> = [5, 3, 5]
+        .into_iter()
+        .map(|day| {
+            (find_day(day).unwrap(), Input::day(day, "ex"))
+        })
+        .collect();
+    let answers: Vec<Vec<String>> =
+        run_all(&days, &[Part::One, Part::Two])
+            .into_iter()
+            .map(|report| {
+                let report = report.unwrap();
+                report.answers.into_iter().map(|x| x.1).collect()
+            })
+            .collect();
+    assert_eq!(
+        answers,
+        [["35", "46"], ["4361", "467835"], ["35", "46"]]
+    );
+
+    // a failing day doesn't stop the others
+    let days = [
+        (find_day(5).unwrap(), Input::text("seeds: x")),
+        (find_day(5).unwrap(), Input::day(5, "ex")),
+    ];
+    let reports = run_all(&days, &[Part::One]);
+    assert!(reports[0].is_err());
+    assert!(reports[1].is_ok());
+}
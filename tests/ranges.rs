@@ -0,0 +1,68 @@
+use aoc23::ranges::{PiecewiseMap, RangeSet};
+
+fn set(ranges: &[(i64, i64)]) -> RangeSet<i64> {
+    return ranges.iter().map(|(a, b)| *a..*b).collect();
+}
+
+#[test]
+fn normalizes() {
+    let s = set(&[(5, 8), (0, 2), (2, 3), (7, 10), (4, 4)]);
+    assert_eq!(s.ranges(), [0..3, 5..10]);
+    assert_eq!(s.min(), Some(0));
+
+    let mut s = s;
+    s.insert(3..5);
+    assert_eq!(s, set(&[(0, 10)]));
+    assert!(RangeSet::<i64>::new().is_empty());
+}
+
+#[test]
+fn containment() {
+    let s = set(&[(0, 3), (5, 10)]);
+    assert!(s.contains(&0));
+    assert!(s.contains(&9));
+    assert!(!s.contains(&3));
+    assert!(!s.contains(&10));
+    assert!(s.contains_range(&(6..10)));
+    assert!(!s.contains_range(&(2..6)));
+}
+
+#[test]
+fn set_operations() {
+    let a = set(&[(0, 5), (10, 15)]);
+    let b = set(&[(3, 12), (20, 25)]);
+    assert_eq!(a.union(&b).ranges(), [0..15, 20..25]);
+    assert_eq!(a.intersection(&b).ranges(), [3..5, 10..12]);
+    assert_eq!(a.difference(&b).ranges(), [0..3, 12..15]);
+    assert_eq!(b.difference(&a).ranges(), [5..10, 20..25]);
+
+    let holes = set(&[(1, 2), (3, 4)]);
+    assert_eq!(
+        set(&[(0, 5)]).difference(&holes).ranges(),
+        [0..1, 2..3, 4..5]
+    );
+    assert!(a.difference(&a).is_empty());
+}
+
+#[test]
+fn piecewise_map() {
+    // seed-to-soil from the example
+    let mut map = PiecewiseMap::new();
+    map.insert(98..100, 50).unwrap();
+    map.insert(50..98, 52).unwrap();
+    assert_eq!(map.map(79), 81);
+    assert_eq!(map.map(99), 51);
+    assert_eq!(map.map(10), 10);
+
+    // split into the unmapped part, then each piece's image
+    assert_eq!(
+        map.map_range(&(40..99)),
+        [40..50, 52..100, 50..51]
+    );
+    let unmapped = map.map_range(&(0..10));
+    assert_eq!(unmapped.len(), 1);
+    assert_eq!(unmapped[0], 0..10);
+
+    let images = map.map_set(&set(&[(40, 99)]));
+    assert_eq!(images, set(&[(40, 51), (52, 100)]));
+}
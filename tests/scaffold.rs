@@ -0,0 +1,82 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use aoc23::scaffold::new_day;
+
+/// A scratch copy of the files `new_day` edits.
+fn scratch_repo(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!(
+        "aoc23-scaffold-{}-{}",
+        name,
+        process::id()
+    ));
+    let _ = fs::remove_dir_all(&root);
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for file in
+        ["src/lib.rs", "src/registry.rs", "tests/examples.rs"]
+    {
+        let to = root.join(file);
+        fs::create_dir_all(to.parent().unwrap()).unwrap();
+        fs::copy(repo.join(file), to).unwrap();
+    }
+    return root;
+}
+
+#[test]
+fn scaffolds_and_registers_a_day() {
+    let root = scratch_repo("new");
+    new_day(&root, 10).unwrap();
+
+    let module =
+        fs::read_to_string(root.join("src/10rs/mod.rs")).unwrap();
+    assert!(module.contains("impl Solution for Day10 {"));
+    assert!(!module.contains("DayNN"));
+    assert!(root.join("src/10rs/ex.in.txt").exists());
+    assert!(root.join("src/10rs/in.txt").exists());
+
+    let lib =
+        fs::read_to_string(root.join("src/lib.rs")).unwrap();
+    assert!(lib.contains(
+        "pub mod day09;\n#[path = \"10rs/mod.rs\"]\npub mod day10;\n"
+    ));
+    let registry =
+        fs::read_to_string(root.join("src/registry.rs")).unwrap();
+    assert!(registry.contains(
+        "::Day09>),\n    (10, solve::<crate::day10::Day10>),\n];"
+    ));
+    let examples =
+        fs::read_to_string(root.join("tests/examples.rs"))
+            .unwrap();
+    assert!(examples.contains("fn day10() {"));
+
+    // days are kept in order
+    new_day(&root, 12).unwrap();
+    new_day(&root, 11).unwrap();
+    let lib =
+        fs::read_to_string(root.join("src/lib.rs")).unwrap();
+    let day11 = lib.find("pub mod day11;").unwrap();
+    assert!(lib.find("pub mod day10;").unwrap() < day11);
+    assert!(day11 < lib.find("pub mod day12;").unwrap());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn refuses_existing_days() {
+    let root = scratch_repo("existing");
+    let lib =
+        fs::read_to_string(root.join("src/lib.rs")).unwrap();
+
+    assert!(new_day(&root, 5).is_err());
+    assert!(new_day(&root, 26).is_err());
+    // nothing was half written
+    assert_eq!(
+        fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+        lib
+    );
+    assert!(!root.join("src/05rs").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,114 @@
+use aoc23::day04::{Cascade, Day04, Overrun, Scratchcard};
+use aoc23::input::Line;
+use aoc23::{Input, Solution};
+use num_bigint::BigUint;
+
+fn example() -> Vec<Scratchcard> {
+    return Day04::parse(&Input::day(4, "ex")).unwrap();
+}
+
+/// A card with `matches` winning numbers, all of them ours.
+fn card(number: u32, matches: u32) -> Scratchcard {
+    let numbers: Vec<u32> = (0..matches).collect();
+    return Scratchcard {
+        number,
+        winning: numbers.clone(),
+        numbers,
+    };
+}
+
+#[test]
+fn scores_cards() {
+    let cards = example();
+    let matches: Vec<usize> =
+        cards.iter().map(|x| x.matches()).collect();
+    assert_eq!(matches, [4, 2, 2, 1, 0, 0]);
+    let points: Vec<BigUint> =
+        cards.iter().map(|x| x.points()).collect();
+    assert_eq!(points, [8u32, 2, 2, 1, 0, 0].map(BigUint::from));
+
+    let line = Line {
+        number: 1,
+        text: "Card  12: 1 2 |  2  3".to_string(),
+    };
+    let card = Scratchcard::parse(&line).unwrap();
+    assert_eq!((card.number, card.matches()), (12, 1));
+    // points aren't limited to what fits in a machine word
+    assert_eq!(
+        self::card(1, 100).points(),
+        BigUint::from(1u8) << 99
+    );
+}
+
+#[test]
+fn traces_copies() {
+    let cascade = Cascade::new(&example());
+    let copies: Vec<BigUint> = (1..=6)
+        .map(|x| cascade.copies(x).unwrap().clone())
+        .collect();
+    assert_eq!(copies, [1u32, 2, 4, 8, 14, 1].map(BigUint::from));
+    assert_eq!(cascade.total(), BigUint::from(30u8));
+    assert_eq!(cascade.copies(7), None);
+
+    let sources: Vec<(u32, u32)> = cascade
+        .sources(4)
+        .into_iter()
+        .map(|(card, won)| (card, u32::try_from(won).unwrap()))
+        .collect();
+    assert_eq!(sources, [(1, 1), (2, 2), (3, 4)]);
+    assert!(cascade.sources(1).is_empty());
+    assert!(cascade.overruns().is_empty());
+}
+
+#[test]
+fn detects_overruns() {
+    let cards = [card(1, 1), card(2, 3), card(3, 0), card(4, 2)];
+    let cascade = Cascade::new(&cards);
+    assert_eq!(
+        cascade.overruns(),
+        [
+            Overrun { card: 2, past: 1 },
+            Overrun { card: 4, past: 2 }
+        ]
+    );
+    // the cards that do exist still get their copies
+    assert_eq!(cascade.copies(4), Some(&BigUint::from(3u8)));
+
+    let err = Day04::part2(&cards.to_vec()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "inconsistent input: card 2's matches run 1 past the last card"
+    );
+}
+
+#[test]
+fn snowballs_past_u64() {
+    // every card wins all the ones after it, so copies double
+    let cards: Vec<Scratchcard> =
+        (1..=80).map(|x| card(x, 80 - x)).collect();
+    let cascade = Cascade::new(&cards);
+    assert_eq!(
+        cascade.copies(80),
+        Some(&(BigUint::from(1u8) << 79))
+    );
+    assert_eq!(cascade.total(), (BigUint::from(1u8) << 80) - 1u8);
+}
+
+#[test]
+fn rejects_bad_cards() {
+    let cases = [
+        (
+            "Card 1: 1 2 3",
+            "line 1: expected \"Card N: ... | ...\"",
+        ),
+        ("Card 1: 1 x | 3", "line 1:"),
+        (
+            "Card 1: 1 | 2\nCard 3: 1 | 2",
+            "line 2: expected card 2",
+        ),
+    ];
+    for (text, expected) in cases {
+        let err = Day04::parse(&Input::text(text)).unwrap_err();
+        assert!(err.to_string().starts_with(expected), "{}", err);
+    }
+}
@@ -0,0 +1,283 @@
+mod support;
+
+use std::cell::RefCell;
+use std::env;
+use std::fs;
+use std::process::{self, Command};
+use std::time::{Duration, SystemTime};
+
+use aoc23::solution::Part;
+use aoc23::submit::{
+    parse_response, submit_answer, HttpSubmitter, Refusal,
+    Response, SubmissionLog, Submitter, Verdict,
+};
+use aoc23::Result;
+use support::StubServer;
+
+const RIGHT: &str = "<main><article><p>That's the right answer! \
+    You are <em>one gold star</em> closer.</p></article></main>";
+const TOO_HIGH: &str =
+    "<article><p>That's not the right answer; \
+    your answer is too high.  If you're stuck, make sure you're \
+    using the full input data.  Please wait one minute before \
+    trying again.</p></article>";
+const TOO_LOW: &str = "<article><p>That's not the right answer; \
+    your answer is too low.  Please wait 5 minutes before trying \
+    again.</p></article>";
+const WRONG: &str = "<article><p>That's not the right answer.  \
+    Please wait one minute before trying again.</p></article>";
+const TOO_SOON: &str = "<article><p>You gave an answer too \
+    recently; you have to wait after submitting an answer before \
+    trying again.  You have 4m 30s left to wait.</p></article>";
+const WRONG_LEVEL: &str =
+    "<article><p>You don't seem to be solving \
+    the right level.  Did you already complete it?</p></article>";
+
+fn response(verdict: Verdict, wait: Option<u64>) -> Response {
+    return Response {
+        verdict,
+        wait: wait.map(Duration::from_secs),
+    };
+}
+
+#[test]
+fn parses_responses() {
+    let cases = [
+        (RIGHT, response(Verdict::Correct, None)),
+        (TOO_HIGH, response(Verdict::TooHigh, Some(60))),
+        (TOO_LOW, response(Verdict::TooLow, Some(300))),
+        (WRONG, response(Verdict::Wrong, Some(60))),
+        (TOO_SOON, response(Verdict::TooSoon, Some(270))),
+        (WRONG_LEVEL, response(Verdict::WrongLevel, None)),
+    ];
+    for (html, expected) in cases {
+        assert_eq!(parse_response(html).unwrap(), expected);
+    }
+    assert!(parse_response("<html>Puzzle inputs</html>").is_err());
+}
+
+#[test]
+fn posts_the_answer() {
+    let server = StubServer::start(vec![(200, RIGHT)]);
+    let submitter =
+        HttpSubmitter::new("abc123").with_base_url(server.url());
+    let response =
+        submitter.submit(2023, 5, Part::Two, "5200543").unwrap();
+    assert_eq!(response.verdict, Verdict::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/5/answer");
+    assert_eq!(
+        requests[0].header("cookie"),
+        Some("session=abc123")
+    );
+    assert_eq!(requests[0].body, "level=2&answer=5200543");
+}
+
+#[test]
+fn refuses_known_wrong_answers() {
+    let now = SystemTime::now();
+    let mut log = SubmissionLog::default();
+    log.record(
+        1,
+        Part::One,
+        "500",
+        &response(Verdict::TooHigh, None),
+        now,
+    );
+    log.record(
+        1,
+        Part::One,
+        "100",
+        &response(Verdict::TooLow, None),
+        now,
+    );
+    log.record(
+        1,
+        Part::One,
+        "250",
+        &response(Verdict::Wrong, None),
+        now,
+    );
+
+    let check = |answer| log.check(1, Part::One, answer, now);
+    assert_eq!(check("250"), Some(Refusal::KnownWrong));
+    assert_eq!(
+        check("700"),
+        Some(Refusal::TooHigh {
+            bound: "500".to_string()
+        })
+    );
+    assert_eq!(
+        check("99"),
+        Some(Refusal::TooLow {
+            bound: "100".to_string()
+        })
+    );
+    assert_eq!(check("300"), None);
+    // bounds only apply to their own part
+    assert_eq!(log.check(1, Part::Two, "700", now), None);
+
+    log.record(
+        1,
+        Part::One,
+        "300",
+        &response(Verdict::Correct, None),
+        now,
+    );
+    assert_eq!(
+        log.check(1, Part::One, "301", now),
+        Some(Refusal::Solved {
+            answer: "300".to_string()
+        })
+    );
+}
+
+#[test]
+fn waits_out_the_cooldown() {
+    let now = SystemTime::now();
+    let mut log = SubmissionLog::default();
+    log.record(
+        2,
+        Part::One,
+        "7",
+        &response(Verdict::Wrong, Some(60)),
+        now,
+    );
+    assert_eq!(
+        log.check(2, Part::One, "8", now),
+        Some(Refusal::Wait(Duration::from_secs(60)))
+    );
+    let later = now + Duration::from_secs(61);
+    assert_eq!(log.check(2, Part::One, "8", later), None);
+
+    // a too-soon answer wasn't checked, so it can be sent again
+    log.record(
+        2,
+        Part::One,
+        "8",
+        &response(Verdict::TooSoon, Some(5)),
+        later,
+    );
+    let much_later = later + Duration::from_secs(5);
+    assert_eq!(log.check(2, Part::One, "8", much_later), None);
+}
+
+#[test]
+fn log_round_trips() {
+    let path = env::temp_dir()
+        .join(format!("aoc23-submit-{}", process::id()))
+        .join("submissions.toml");
+    let now = SystemTime::now();
+    let mut log = SubmissionLog::default();
+    log.record(
+        3,
+        Part::One,
+        "9",
+        &response(Verdict::TooLow, Some(60)),
+        now,
+    );
+    // quotes and control characters need toml's escaping
+    log.record(
+        3,
+        Part::Two,
+        "x\u{1b}\"y\"",
+        &response(Verdict::Wrong, None),
+        now,
+    );
+    log.record(
+        4,
+        Part::One,
+        "13",
+        &response(Verdict::Correct, None),
+        now,
+    );
+    log.save(&path).unwrap();
+
+    let loaded = SubmissionLog::load(&path).unwrap();
+    for (day, part) in
+        [(3, Part::One), (3, Part::Two), (4, Part::One)]
+    {
+        assert_eq!(loaded.get(day, part), log.get(day, part));
+    }
+    assert!(matches!(
+        loaded.check(3, Part::One, "10", now),
+        Some(Refusal::Wait(_))
+    ));
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+/// Remembers what it was sent and says every answer is too high.
+struct FakeSubmitter {
+    sent: RefCell<Vec<String>>,
+}
+
+impl Submitter for FakeSubmitter {
+    fn submit(
+        &self,
+        _year: u32,
+        _day: u32,
+        _part: Part,
+        answer: &str,
+    ) -> Result<Response> {
+        self.sent.borrow_mut().push(answer.to_string());
+        return Ok(response(Verdict::TooHigh, None));
+    }
+}
+
+#[test]
+fn never_resubmits() {
+    let submitter = FakeSubmitter {
+        sent: RefCell::new(Vec::new()),
+    };
+    let mut log = SubmissionLog::default();
+    let mut submit = |answer| {
+        submit_answer(
+            &submitter,
+            &mut log,
+            2023,
+            6,
+            Part::One,
+            answer,
+        )
+    };
+    assert_eq!(submit("40").unwrap().verdict, Verdict::TooHigh);
+    assert!(submit("40").is_err());
+    assert!(submit("41").is_err());
+    assert_eq!(submit("39").unwrap().verdict, Verdict::TooHigh);
+    assert_eq!(*submitter.sent.borrow(), vec!["40", "39"]);
+}
+
+#[test]
+fn refusals_need_no_session() {
+    let cache = env::temp_dir()
+        .join(format!("aoc23-submit-offline-{}", process::id()));
+    fs::create_dir_all(cache.join("2023")).unwrap();
+    // only read if the real input isn't checked in
+    fs::write(cache.join("2023").join("01.txt"), "1abc2\n")
+        .unwrap();
+    let mut log = SubmissionLog::default();
+    log.record(
+        1,
+        Part::One,
+        "0",
+        &response(Verdict::TooSoon, Some(600)),
+        SystemTime::now(),
+    );
+    log.save(&cache.join("2023").join("submissions.toml"))
+        .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "--day", "1", "--part", "1"])
+        .env("AOC_CACHE_DIR", &cache)
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&cache).unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("wait another"), "{}", stderr);
+    assert!(!stderr.contains("AOC_SESSION"), "{}", stderr);
+}
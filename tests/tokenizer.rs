@@ -0,0 +1,64 @@
+use aoc23::day01::{DigitMatch, Vocabulary};
+
+fn values(matches: &[DigitMatch]) -> Vec<u32> {
+    return matches.iter().map(|x| x.value).collect();
+}
+
+#[test]
+fn overlapping_words() {
+    let english = Vocabulary::english();
+    let line = "xeightwone3";
+    let first = english.first(line).unwrap();
+    assert_eq!(
+        (first.value, first.start, first.end()),
+        (8, 1, 6)
+    );
+    let last = english.last(line).unwrap();
+    assert_eq!((last.value, last.start, last.len), (3, 10, 1));
+    assert_eq!(values(&english.find_all(line)), [8, 2, 1, 3]);
+
+    // the last digit shares its first letter with the one before
+    let last = english.last("7pqrstsixteen").unwrap();
+    assert_eq!((last.value, last.start), (6, 6));
+    assert_eq!(english.last("oneight").unwrap().value, 8);
+}
+
+#[test]
+fn numerals_only() {
+    let numerals = Vocabulary::numerals();
+    assert_eq!(
+        values(&numerals.find_all("a1b2c3d4e5f")),
+        [1, 2, 3, 4, 5]
+    );
+    assert_eq!(numerals.first("eightwothree"), None);
+    assert_eq!(numerals.last(""), None);
+}
+
+#[test]
+fn custom_vocabularies() {
+    let english = Vocabulary::english().with_word("zero", 0);
+    assert_eq!(values(&english.find_all("zerone")), [0, 1]);
+
+    // no numerals, and longer words win where both match
+    let german = Vocabulary::default()
+        .with_word("eins", 1)
+        .with_word("ein", 1)
+        .with_word("zwei", 2)
+        .with_word("sieben", 7)
+        .with_word("siebzehn", 17);
+    let matches = german.find_all("3zweins siebzehn");
+    assert_eq!(values(&matches), [2, 1, 17]);
+    assert_eq!(matches[1].len, 4);
+    // positions are in bytes, so they index straight into the line
+    let line = "über·sieben";
+    let last = german.last(line).unwrap();
+    assert_eq!(&line[last.start..last.end()], "sieben");
+}
+
+#[test]
+fn repeated_words_take_the_later_value() {
+    let english = Vocabulary::english().with_word("one", 7);
+    assert_eq!(english.first("one").unwrap().value, 7);
+    assert_eq!(english.last("one").unwrap().value, 7);
+    assert_eq!(values(&english.find_all("one")), [7]);
+}